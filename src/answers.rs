@@ -0,0 +1,408 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    input::InputError,
+    solution::{Answer, Part, RegisteredSolution},
+};
+
+/// Environment variable overriding the file confirmed answers are read from.
+pub const ANSWERS_FILE_ENV: &str = "AOC_ANSWERS_FILE";
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// An answer as written in the answers file: either an integer or a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(u64),
+    Text(String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RecordedDay {
+    part1: Option<Recorded>,
+    part2: Option<Recorded>,
+}
+
+impl RecordedDay {
+    fn into_parts(self) -> [Option<Answer>; 2] {
+        [self.part1, self.part2].map(|part| {
+            part.map(|recorded| match recorded {
+                Recorded::Number(n) => Answer::Number(n),
+                Recorded::Text(s) => Answer::Text(s),
+            })
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Toml(toml::de::Error),
+    /// A table name that isn't `dayNN`.
+    InvalidDay(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => {
+                write!(f, "cannot read answers from {}: {source}", path.display())
+            }
+            AnswersError::Toml(e) => write!(f, "invalid answers file: {e}"),
+            AnswersError::InvalidDay(name) => {
+                write!(f, "invalid answers file: expected `dayNN`, got `[{name}]`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Io { source, .. } => Some(source),
+            AnswersError::Toml(e) => Some(e),
+            AnswersError::InvalidDay(_) => None,
+        }
+    }
+}
+
+/// Confirmed answers per day and part, stored as TOML:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1234
+/// part2 = "some,text"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Answers {
+    days: BTreeMap<u32, [Option<Answer>; 2]>,
+}
+
+impl Answers {
+    pub fn parse(toml: &str) -> Result<Self, AnswersError> {
+        let recorded: BTreeMap<String, RecordedDay> =
+            toml::from_str(toml).map_err(AnswersError::Toml)?;
+        let days = recorded
+            .into_iter()
+            .map(|(name, day)| {
+                let number = name
+                    .strip_prefix("day")
+                    .and_then(|n| n.parse().ok())
+                    .ok_or(AnswersError::InvalidDay(name))?;
+                Ok((number, day.into_parts()))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { days })
+    }
+
+    /// Reads the answers file; a file that doesn't exist has no answers.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(toml) => Self::parse(&toml),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AnswersError::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    /// Uses the file from [`ANSWERS_FILE_ENV`] if set, [`DEFAULT_ANSWERS_FILE`] otherwise.
+    pub fn load_from_env() -> Result<Self, AnswersError> {
+        match std::env::var_os(ANSWERS_FILE_ENV) {
+            Some(path) => Self::load(path),
+            None => Self::load(DEFAULT_ANSWERS_FILE),
+        }
+    }
+
+    /// Parses the `part1`/`part2` keys of a single day, without the `[dayNN]` table.
+    pub fn parse_day(toml: &str) -> Result<[Option<Answer>; 2], AnswersError> {
+        Self::day_from_table(toml::from_str(toml).map_err(AnswersError::Toml)?)
+    }
+
+    /// Like [`parse_day`](Self::parse_day), for an already parsed table.
+    pub fn day_from_table(table: toml::Table) -> Result<[Option<Answer>; 2], AnswersError> {
+        let day: RecordedDay = toml::Value::Table(table)
+            .try_into()
+            .map_err(AnswersError::Toml)?;
+        Ok(day.into_parts())
+    }
+
+    pub fn expected(&self, day: u32, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?[part.number() as usize - 1].as_ref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.values().flatten().all(Option::is_none)
+    }
+}
+
+/// How one part compares against its confirmed answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// The part produced an answer, but none is recorded to compare against.
+    Unconfirmed(Answer),
+    /// The part failed to produce an answer, with the reason.
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartCheck {
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+/// Results of running every registered solution against its input and the confirmed answers.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Verification {
+    pub checks: Vec<PartCheck>,
+    /// Days that couldn't be checked because their input doesn't exist.
+    pub skipped: Vec<u32>,
+    /// Days whose input was rejected, with the error.
+    pub failed: Vec<(u32, String)>,
+}
+
+impl Verification {
+    pub fn collect(
+        solutions: &[RegisteredSolution],
+        answers: &Answers,
+        mut read_input: impl FnMut(u32) -> Result<String, InputError>,
+    ) -> Self {
+        let mut verification = Self::default();
+        for solution in solutions {
+            let input = match read_input(solution.day) {
+                Ok(input) => input,
+                Err(InputError::NotFound { .. }) => {
+                    verification.skipped.push(solution.day);
+                    continue;
+                }
+                Err(e) => {
+                    verification.failed.push((solution.day, e.to_string()));
+                    continue;
+                }
+            };
+            verification.check_day(solution, answers, &input);
+        }
+        verification
+    }
+
+    fn check_day(&mut self, solution: &RegisteredSolution, answers: &Answers, input: &str) {
+        let day = solution.day;
+        let results = match solution.run(input, Part::ALL) {
+            Ok(results) => results,
+            Err(e) => {
+                self.failed.push((day, e.to_string()));
+                return;
+            }
+        };
+        for result in results {
+            let outcome = match (answers.expected(day, result.part), result.answer) {
+                (None, Answer::Unimplemented) => continue,
+                (_, Answer::Failed(reason)) => Outcome::Failed(reason),
+                (None, actual) => Outcome::Unconfirmed(actual),
+                (Some(expected), actual) if *expected == actual => Outcome::Correct,
+                (Some(expected), actual) => Outcome::Wrong {
+                    expected: expected.clone(),
+                    actual,
+                },
+            };
+            self.checks.push(PartCheck {
+                day,
+                part: result.part,
+                outcome,
+            });
+        }
+    }
+
+    pub fn wrong(&self) -> impl Iterator<Item = &PartCheck> {
+        self.checks
+            .iter()
+            .filter(|check| matches!(check.outcome, Outcome::Wrong { .. }))
+    }
+
+    /// Whether no part disagreed with its confirmed answer or failed, and every input parsed.
+    pub fn passed(&self) -> bool {
+        self.wrong().next().is_none()
+            && self.failed.is_empty()
+            && !self
+                .checks
+                .iter()
+                .any(|check| matches!(check.outcome, Outcome::Failed(_)))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Wrong { expected, actual } => {
+                write!(f, "WRONG: expected {expected}, got {actual}")
+            }
+            Outcome::Unconfirmed(answer) => write!(f, "unconfirmed: {answer}"),
+            Outcome::Failed(reason) => write!(f, "FAILED: {reason}"),
+        }
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            writeln!(
+                f,
+                "day {:02} part {}: {}",
+                check.day,
+                check.part.number(),
+                check.outcome
+            )?;
+        }
+        for (day, e) in &self.failed {
+            writeln!(f, "day {day:02}: FAILED: {e}")?;
+        }
+        if !self.skipped.is_empty() {
+            let days = self
+                .skipped
+                .iter()
+                .map(|day| format!("{day:02}"))
+                .collect::<Vec<_>>();
+            writeln!(f, "skipped (no input): {}", days.join(", "))?;
+        }
+
+        let count = |f: fn(&Outcome) -> bool| self.checks.iter().filter(|c| f(&c.outcome)).count();
+        write!(
+            f,
+            "{} correct, {} wrong, {} unconfirmed, {} failed, {} skipped",
+            count(|o| *o == Outcome::Correct),
+            count(|o| matches!(o, Outcome::Wrong { .. })),
+            count(|o| matches!(o, Outcome::Unconfirmed(_))),
+            self.failed.len() + count(|o| matches!(o, Outcome::Failed(_))),
+            self.skipped.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::ParseError, solution::Solution};
+
+    struct Double;
+
+    impl Solution for Double {
+        const DAY: u32 = 3;
+
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::in_input(Self::DAY, input, input, "a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            (*input).into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            if *input == 0 {
+                return Answer::Failed("nothing to double".to_owned());
+            }
+            format!("{}", 2 * input).into()
+        }
+    }
+
+    #[test]
+    fn parse_answers() {
+        let answers =
+            Answers::parse("[day03]\npart1 = 5\npart2 = \"10\"\n\n[day04]\npart2 = 7\n").unwrap();
+        assert_eq!(answers.expected(3, Part::One), Some(&Answer::Number(5)));
+        assert_eq!(
+            answers.expected(3, Part::Two),
+            Some(&Answer::Text("10".to_owned()))
+        );
+        assert_eq!(answers.expected(4, Part::One), None);
+        assert_eq!(answers.expected(4, Part::Two), Some(&Answer::Number(7)));
+        assert_eq!(answers.expected(5, Part::One), None);
+
+        assert!(matches!(
+            Answers::parse("[three]\npart1 = 1"),
+            Err(AnswersError::InvalidDay(_))
+        ));
+        assert!(matches!(
+            Answers::parse("[day01]\npart3 = 1"),
+            Err(AnswersError::Toml(_))
+        ));
+        assert!(Answers::load("does/not/exist.toml").unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify() {
+        let solutions = [RegisteredSolution::new::<Double>()];
+        let answers = Answers::parse("[day03]\npart1 = 5\npart2 = \"11\"").unwrap();
+
+        let verification = Verification::collect(&solutions, &answers, |_| Ok("5".to_owned()));
+        assert_eq!(verification.checks[0].outcome, Outcome::Correct);
+        assert_eq!(
+            verification.checks[1].outcome,
+            Outcome::Wrong {
+                expected: Answer::Text("11".to_owned()),
+                actual: Answer::Text("10".to_owned())
+            }
+        );
+        assert!(!verification.passed());
+        assert!(verification
+            .to_string()
+            .ends_with("1 correct, 1 wrong, 0 unconfirmed, 0 failed, 0 skipped"));
+
+        let verification =
+            Verification::collect(&solutions, &Answers::default(), |_| Ok("6".to_owned()));
+        assert!(verification.passed());
+        assert_eq!(
+            verification.checks[0].outcome,
+            Outcome::Unconfirmed(Answer::Number(6))
+        );
+
+        let verification = Verification::collect(&solutions, &answers, |day| {
+            Err(InputError::NotFound {
+                day,
+                path: "day03.txt".into(),
+            })
+        });
+        assert!(verification.passed());
+        assert_eq!(verification.skipped, vec![3]);
+        assert!(verification.checks.is_empty());
+
+        let verification = Verification::collect(&solutions, &answers, |_| Ok("x".to_owned()));
+        assert!(!verification.passed());
+        assert_eq!(verification.failed.len(), 1);
+
+        let verification =
+            Verification::collect(&solutions, &Answers::default(), |_| Ok("0".to_owned()));
+        assert!(!verification.passed());
+        assert_eq!(
+            verification.checks[1].outcome,
+            Outcome::Failed("nothing to double".to_owned())
+        );
+        assert!(verification
+            .to_string()
+            .ends_with("0 correct, 0 wrong, 1 unconfirmed, 1 failed, 0 skipped"));
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use crate::{
+    parse::ParseError,
+    solution::{Part, RegisteredSolution},
+};
+
+pub const DEFAULT_RUNS: usize = 10;
+
+/// Summary of repeated measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes the samples; the median of an even number of samples is the lower middle one.
+    pub fn new(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[(samples.len() - 1) / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of repeatedly running one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+    pub total: Stats,
+}
+
+impl DayBench {
+    /// Parses and solves `parts` of `input` `runs` times.
+    pub fn run(
+        solution: &RegisteredSolution,
+        input: &str,
+        parts: &[Part],
+        runs: usize,
+    ) -> Result<Self, ParseError> {
+        assert!(runs > 0, "at least one run is needed");
+        let mut parse = vec![];
+        let mut part_times = vec![vec![]; parts.len()];
+        let mut total = vec![];
+        for _ in 0..runs {
+            let run = solution.run_timed(input, parts)?;
+            parse.push(run.parse_time);
+            for (times, time) in part_times.iter_mut().zip(&run.part_times) {
+                times.push(*time);
+            }
+            total.push(run.total_time());
+        }
+
+        Ok(Self {
+            day: solution.day,
+            runs,
+            parse: Stats::new(&mut parse),
+            parts: parts
+                .iter()
+                .zip(&mut part_times)
+                .map(|(part, times)| (*part, Stats::new(times)))
+                .collect(),
+            total: Stats::new(&mut total),
+        })
+    }
+}
+
+/// Formats a duration with a unit suited to its magnitude, e.g. `12.35ms`.
+pub fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>10} {:>10} {:>10}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.max)
+        )
+    }
+}
+
+impl Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {:02} ({} runs)  {:>10} {:>10} {:>10}",
+            self.day, self.runs, "min", "median", "max"
+        )?;
+        writeln!(f, "  parse   {}", self.parse)?;
+        for (part, stats) in &self.parts {
+            writeln!(f, "  part {}  {stats}", part.number())?;
+        }
+        write!(f, "  total   {}", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01::Day01;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(&mut [ms(5), ms(1), ms(3), ms(2)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(5)
+            }
+        );
+        assert_eq!(Stats::new(&mut [ms(7)]).median, ms(7));
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+    }
+
+    #[test]
+    fn bench_day() {
+        let solution = RegisteredSolution::new::<Day01>();
+        let bench = DayBench::run(&solution, "1 2\n3 4\n", &[Part::Two], 3).unwrap();
+        assert_eq!(bench.day, 1);
+        assert_eq!(bench.parts.len(), 1);
+        assert_eq!(bench.parts[0].0, Part::Two);
+        assert!(bench.total.min >= bench.parse.min);
+        assert!(bench.to_string().contains("\n  part 2  "));
+    }
+}
@@ -0,0 +1,383 @@
+use std::{process::ExitCode, time::Duration};
+
+use aoc2024::{
+    answers::{Answers, Verification, ANSWERS_FILE_ENV, DEFAULT_ANSWERS_FILE},
+    bench::{format_duration, DayBench, DEFAULT_RUNS},
+    calendar::Calendar,
+    days::{self, day22},
+    input::{InputLocator, InputSource},
+    parallel,
+    solution::{Answer, Part, RegisteredSolution, Solution},
+};
+
+const USAGE: &str =
+    "usage: aoc run <DAY|all> [--part <1|2>] [--input <PATH|->] [--variant <NAME>] [--time]
+       aoc bench <DAY|all> [--part <1|2>] [--input <PATH|->] [--variant <NAME>] [--runs <N>]
+       aoc calendar [--variant <NAME>]
+       aoc verify [--answers <PATH>]
+       aoc explain <DAY> [--input <PATH|->] [--variant <NAME>] [--top <K>]
+
+The input directory defaults to `input` and can be changed with AOC_INPUT_DIR.
+Confirmed answers are read from `answers.toml`, or AOC_ANSWERS_FILE if set.
+Every command accepts `--threads <N>` when built with the `parallel` feature.";
+
+#[derive(Debug)]
+enum Command {
+    Run {
+        days: Vec<&'static RegisteredSolution>,
+        parts: Vec<Part>,
+        source: InputSource,
+        /// Whether to report how long parsing and each part took.
+        time: bool,
+    },
+    /// Runs each day repeatedly and reports min/median/max times.
+    Bench {
+        days: Vec<&'static RegisteredSolution>,
+        parts: Vec<Part>,
+        source: InputSource,
+        runs: usize,
+    },
+    /// Runs every day to report which parts are solved.
+    Calendar { source: InputSource },
+    /// Compares every day's answers with the confirmed ones.
+    Verify { answers: Option<String> },
+    /// Prints how a day's answer comes about, for days that support it.
+    Explain {
+        day: u32,
+        source: InputSource,
+        top: usize,
+    },
+}
+
+fn parse_days(arg: &str) -> Result<Vec<&'static RegisteredSolution>, String> {
+    if arg == "all" {
+        return Ok(days::ALL.iter().collect());
+    }
+    let day = arg
+        .parse::<u32>()
+        .map_err(|_| format!("invalid day `{arg}`"))?;
+    days::find(day)
+        .map(|s| vec![s])
+        .ok_or_else(|| format!("day {day} is not implemented"))
+}
+
+/// Removes `--threads <N>` from the arguments, which applies to every command.
+fn take_threads(args: &mut Vec<String>) -> Result<Option<usize>, String> {
+    let Some(i) = args.iter().position(|arg| arg == "--threads") else {
+        return Ok(None);
+    };
+    if !cfg!(feature = "parallel") {
+        return Err("--threads requires the `parallel` feature".to_owned());
+    }
+    let value = args.get(i + 1).ok_or("missing value for --threads")?;
+    let threads = value
+        .parse()
+        .ok()
+        .filter(|threads| *threads > 0)
+        .ok_or_else(|| format!("invalid number of threads `{value}`"))?;
+    args.drain(i..i + 2);
+    Ok(Some(threads))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let command = args.next().ok_or("missing command")?;
+    match command.as_str() {
+        "run" | "bench" => {
+            let is_bench = command == "bench";
+            let days = parse_days(&args.next().ok_or("missing day")?)?;
+            let mut parts = Part::ALL.to_vec();
+            let mut source = InputSource::Default;
+            let mut time = false;
+            let mut runs = DEFAULT_RUNS;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--part" => {
+                        let value = args.next().ok_or("missing value for --part")?;
+                        let part = value
+                            .parse()
+                            .ok()
+                            .and_then(Part::from_number)
+                            .ok_or_else(|| format!("invalid part `{value}`"))?;
+                        parts = vec![part];
+                    }
+                    "--input" => {
+                        let value = args.next().ok_or("missing value for --input")?;
+                        source = InputSource::from_arg(&value);
+                    }
+                    "--variant" => {
+                        let value = args.next().ok_or("missing value for --variant")?;
+                        source = InputSource::Variant(value);
+                    }
+                    "--time" if !is_bench => time = true,
+                    "--runs" if is_bench => {
+                        let value = args.next().ok_or("missing value for --runs")?;
+                        runs = value
+                            .parse()
+                            .ok()
+                            .filter(|runs| *runs > 0)
+                            .ok_or_else(|| format!("invalid number of runs `{value}`"))?;
+                    }
+                    _ => return Err(format!("unknown argument `{arg}`")),
+                }
+            }
+
+            if days.len() > 1 && matches!(source, InputSource::Path(_) | InputSource::Stdin) {
+                return Err("--input can only be used with a single day".to_owned());
+            }
+
+            if is_bench {
+                Ok(Command::Bench {
+                    days,
+                    parts,
+                    source,
+                    runs,
+                })
+            } else {
+                Ok(Command::Run {
+                    days,
+                    parts,
+                    source,
+                    time,
+                })
+            }
+        }
+        "calendar" => {
+            let mut source = InputSource::Default;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--variant" => {
+                        let value = args.next().ok_or("missing value for --variant")?;
+                        source = InputSource::Variant(value);
+                    }
+                    _ => return Err(format!("unknown argument `{arg}`")),
+                }
+            }
+            Ok(Command::Calendar { source })
+        }
+        "verify" => {
+            let mut answers = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--answers" => {
+                        answers = Some(args.next().ok_or("missing value for --answers")?);
+                    }
+                    _ => return Err(format!("unknown argument `{arg}`")),
+                }
+            }
+            Ok(Command::Verify { answers })
+        }
+        "explain" => {
+            let value = args.next().ok_or("missing day")?;
+            let day = value
+                .parse::<u32>()
+                .map_err(|_| format!("invalid day `{value}`"))?;
+            if day != 22 {
+                return Err(format!("day {day} has no explain mode"));
+            }
+            let mut source = InputSource::Default;
+            let mut top = 5;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input" => {
+                        let value = args.next().ok_or("missing value for --input")?;
+                        source = InputSource::from_arg(&value);
+                    }
+                    "--variant" => {
+                        let value = args.next().ok_or("missing value for --variant")?;
+                        source = InputSource::Variant(value);
+                    }
+                    "--top" => {
+                        let value = args.next().ok_or("missing value for --top")?;
+                        top = value
+                            .parse()
+                            .map_err(|_| format!("invalid number `{value}`"))?;
+                    }
+                    _ => return Err(format!("unknown argument `{arg}`")),
+                }
+            }
+            Ok(Command::Explain { day, source, top })
+        }
+        _ => Err(format!("unknown command `{command}`")),
+    }
+}
+
+fn run(days: &[&RegisteredSolution], parts: &[Part], source: &InputSource, time: bool) -> ExitCode {
+    let locator = InputLocator::from_env();
+    let mut ret = ExitCode::SUCCESS;
+    let mut total = Duration::ZERO;
+    for solution in days {
+        let input = match locator.read(solution.day, source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ret = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let run = match solution.run_timed(&input, parts) {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ret = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        if time {
+            println!(
+                "day {:02} parse: {}",
+                solution.day,
+                format_duration(run.parse_time)
+            );
+        }
+        for (result, elapsed) in run.results.iter().zip(&run.part_times) {
+            print!(
+                "day {:02} part {}: {}",
+                solution.day,
+                result.part.number(),
+                result.answer
+            );
+            if time {
+                print!(" ({})", format_duration(*elapsed));
+            }
+            println!();
+            if matches!(result.answer, Answer::Failed(_)) {
+                ret = ExitCode::FAILURE;
+            }
+        }
+        total += run.total_time();
+    }
+    if time && days.len() > 1 {
+        println!("total: {}", format_duration(total));
+    }
+    ret
+}
+
+fn bench(
+    days: &[&RegisteredSolution],
+    parts: &[Part],
+    source: &InputSource,
+    runs: usize,
+) -> ExitCode {
+    let locator = InputLocator::from_env();
+    let mut ret = ExitCode::SUCCESS;
+    let mut total = Duration::ZERO;
+    for solution in days {
+        let bench = locator
+            .read(solution.day, source)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                DayBench::run(solution, &input, parts, runs).map_err(|e| e.to_string())
+            });
+        match bench {
+            Ok(bench) => {
+                println!("{bench}");
+                total += bench.total.median;
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                ret = ExitCode::FAILURE;
+            }
+        }
+    }
+    if days.len() > 1 {
+        println!("total (sum of medians): {}", format_duration(total));
+    }
+    ret
+}
+
+fn verify(answers: Option<String>) -> ExitCode {
+    let path = answers
+        .or_else(|| std::env::var(ANSWERS_FILE_ENV).ok())
+        .unwrap_or_else(|| DEFAULT_ANSWERS_FILE.to_owned());
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if answers.is_empty() {
+        eprintln!("warning: no confirmed answers in {path}");
+    }
+
+    let locator = InputLocator::from_env();
+    let verification = Verification::collect(days::ALL, &answers, |day| {
+        locator.read(day, &InputSource::Default)
+    });
+    println!("{verification}");
+    if verification.passed() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn explain(day: u32, source: &InputSource, top: usize) -> ExitCode {
+    let input = match InputLocator::from_env().read(day, source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match day22::Day22::parse(&input) {
+        Ok(secret_numbers) => {
+            let explanation = day22::explain(&secret_numbers, day22::SECRETS_GENERATED, top);
+            println!("{explanation}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = match take_threads(&mut args).and_then(|threads| {
+        if let Some(threads) = threads {
+            parallel::set_threads(threads);
+        }
+        parse_args(args.into_iter())
+    }) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Command::Run {
+            days,
+            parts,
+            source,
+            time,
+        } => run(&days, &parts, &source, time),
+        Command::Bench {
+            days,
+            parts,
+            source,
+            runs,
+        } => bench(&days, &parts, &source, runs),
+        Command::Calendar { source } => {
+            let locator = InputLocator::from_env();
+            match Calendar::collect(days::ALL, |day| locator.read(day, &source)) {
+                Ok(calendar) => {
+                    println!("{calendar}");
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Verify { answers } => verify(answers),
+        Command::Explain { day, source, top } => explain(day, &source, top),
+    }
+}
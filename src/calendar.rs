@@ -0,0 +1,353 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+    input::InputError,
+    solution::{Answer, Part, RegisteredSolution},
+};
+
+/// The last day of the calendar. Its second part has no puzzle; the star is awarded once all others are.
+pub const LAST_DAY: u32 = 25;
+
+/// What is known about one part of one day after trying to run it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartStatus {
+    /// No solution is registered for the day.
+    Missing,
+    /// The day has no input, so it's unknown whether the part is implemented.
+    NoInput,
+    /// The solution rejected its input.
+    ParseFailed(String),
+    /// The solution exists, but doesn't implement this part.
+    Unimplemented,
+    /// Solving the part panicked or failed, with the reason.
+    Failed(String),
+    Answered(Answer),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u32,
+    /// Statuses of part 1 and 2.
+    pub parts: [PartStatus; 2],
+}
+
+impl DayReport {
+    pub fn part(&self, part: Part) -> &PartStatus {
+        &self.parts[part.number() as usize - 1]
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => (*message).to_owned(),
+            Err(_) => "panicked".to_owned(),
+        },
+    }
+}
+
+/// Which days and parts of the calendar are solved, derived by running the registered solutions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calendar {
+    pub days: Vec<DayReport>,
+}
+
+impl Calendar {
+    /// Runs every registered solution on the input `read_input` returns for its day.
+    ///
+    /// Each part runs on its own, so a part that panics is reported as failed without affecting
+    /// the other parts and days. A day whose input doesn't exist is reported as having none; any
+    /// other error reading an input is returned.
+    pub fn collect(
+        solutions: &[RegisteredSolution],
+        mut read_input: impl FnMut(u32) -> Result<String, InputError>,
+    ) -> Result<Self, InputError> {
+        let days = (1..=LAST_DAY)
+            .map(|day| {
+                let Some(solution) = solutions.iter().find(|s| s.day == day) else {
+                    return Ok(DayReport {
+                        day,
+                        parts: [PartStatus::Missing, PartStatus::Missing],
+                    });
+                };
+                let input = match read_input(day) {
+                    Ok(input) => input,
+                    Err(InputError::NotFound { .. }) => {
+                        return Ok(DayReport {
+                            day,
+                            parts: [PartStatus::NoInput, PartStatus::NoInput],
+                        })
+                    }
+                    Err(e) => return Err(e),
+                };
+                let parts =
+                    [Part::One, Part::Two].map(|part| Self::run_part(solution, &input, part));
+                Ok(DayReport { day, parts })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { days })
+    }
+
+    fn run_part(solution: &RegisteredSolution, input: &str, part: Part) -> PartStatus {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solution.run(input, &[part])));
+        match result {
+            Ok(Ok(mut results)) => match results.pop().unwrap().answer {
+                Answer::Unimplemented => PartStatus::Unimplemented,
+                Answer::Failed(reason) => PartStatus::Failed(reason),
+                answer => PartStatus::Answered(answer),
+            },
+            Ok(Err(e)) => PartStatus::ParseFailed(e.to_string()),
+            Err(payload) => PartStatus::Failed(panic_message(payload)),
+        }
+    }
+
+    fn answered(&self) -> impl Iterator<Item = (u32, Part)> + '_ {
+        self.parts()
+            .filter(|(_, _, status)| matches!(status, PartStatus::Answered(_)))
+            .map(|(day, part, _)| (day, part))
+    }
+
+    fn parts(&self) -> impl Iterator<Item = (u32, Part, &PartStatus)> {
+        self.days.iter().flat_map(|report| {
+            Part::ALL
+                .iter()
+                .map(move |part| (report.day, *part, report.part(*part)))
+        })
+    }
+
+    /// Whether this is the last day's second part, which can't be implemented.
+    fn is_free(day: u32, part: Part) -> bool {
+        day == LAST_DAY && part == Part::Two
+    }
+
+    /// One star per answered part, plus the last one if all others are earned.
+    pub fn stars(&self) -> u32 {
+        let answered = self
+            .answered()
+            .filter(|(day, part)| !Self::is_free(*day, *part))
+            .count() as u32;
+        let all_others = 2 * LAST_DAY - 1;
+        if answered == all_others {
+            answered + 1
+        } else {
+            answered
+        }
+    }
+
+    /// Parts that exist but are unimplemented.
+    pub fn stubs(&self) -> impl Iterator<Item = (u32, Part)> + '_ {
+        self.parts()
+            .filter(|(day, part, status)| {
+                **status == PartStatus::Unimplemented && !Self::is_free(*day, *part)
+            })
+            .map(|(day, part, _)| (day, part))
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartStatus::Missing => write!(f, "missing"),
+            PartStatus::NoInput => write!(f, "no input"),
+            PartStatus::ParseFailed(_) => write!(f, "parse error"),
+            PartStatus::Unimplemented => write!(f, "stub"),
+            PartStatus::Failed(_) => write!(f, "failed"),
+            PartStatus::Answered(_) => write!(f, "*"),
+        }
+    }
+}
+
+impl Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "day  part 1       part 2")?;
+        for report in &self.days {
+            let [one, two] = &report.parts;
+            let two = if Self::is_free(report.day, Part::Two) && *two == PartStatus::Unimplemented {
+                if self.stars() == 2 * LAST_DAY {
+                    "*".to_owned()
+                } else {
+                    "free".to_owned()
+                }
+            } else {
+                two.to_string()
+            };
+            writeln!(f, "{:02}   {:<12} {two}", report.day, one.to_string())?;
+        }
+
+        for report in &self.days {
+            // parse errors are the same for both parts
+            if let PartStatus::ParseFailed(e) = &report.parts[0] {
+                writeln!(f, "day {:02}: {e}", report.day)?;
+            }
+        }
+        for (day, part, status) in self.parts() {
+            if let PartStatus::Failed(e) = status {
+                writeln!(f, "day {day:02} part {}: failed: {e}", part.number())?;
+            }
+        }
+
+        let stubs = self
+            .stubs()
+            .map(|(day, part)| format!("day {day:02} part {}", part.number()))
+            .collect::<Vec<_>>();
+        if !stubs.is_empty() {
+            writeln!(f, "stubs: {}", stubs.join(", "))?;
+        }
+        write!(f, "stars: {}/{}", self.stars(), 2 * LAST_DAY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::{parse::ParseError, solution::Solution};
+
+    struct Both;
+
+    impl Solution for Both {
+        const DAY: u32 = 1;
+
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::in_input(Self::DAY, input, input, "a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            (*input).into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            if *input == 0 {
+                return Answer::Failed("nothing to double".to_owned());
+            }
+            (2 * *input).into()
+        }
+    }
+
+    struct OnlyFirst;
+
+    impl Solution for OnlyFirst {
+        const DAY: u32 = 2;
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Answer {
+            "done".into()
+        }
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u32 = 3;
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Answer {
+            "fine".into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            panic!("out of bytes")
+        }
+    }
+
+    #[test]
+    fn report() {
+        let solutions = [
+            RegisteredSolution::new::<Both>(),
+            RegisteredSolution::new::<OnlyFirst>(),
+        ];
+        let calendar = Calendar::collect(&solutions, |_| Ok("21".to_owned())).unwrap();
+
+        assert_eq!(calendar.days.len(), 25);
+        assert_eq!(
+            calendar.days[0].parts,
+            [
+                PartStatus::Answered(Answer::Number(21)),
+                PartStatus::Answered(Answer::Number(42))
+            ]
+        );
+        assert_eq!(calendar.days[1].part(Part::Two), &PartStatus::Unimplemented);
+        assert_eq!(calendar.days[2].part(Part::One), &PartStatus::Missing);
+        assert_eq!(calendar.stars(), 3);
+        assert_eq!(calendar.stubs().collect::<Vec<_>>(), vec![(2, Part::Two)]);
+
+        let report = calendar.to_string();
+        assert!(report.starts_with("day  part 1       part 2\n01   *            *\n02   *            stub\n03   missing      missing\n"));
+        assert!(report.ends_with("stubs: day 02 part 2\nstars: 3/50"));
+
+        let calendar = Calendar::collect(&solutions, |day| {
+            if day == 1 {
+                Ok("x".to_owned())
+            } else {
+                Err(InputError::NotFound {
+                    day,
+                    path: "missing.txt".into(),
+                })
+            }
+        })
+        .unwrap();
+        assert!(matches!(
+            calendar.days[0].parts[0],
+            PartStatus::ParseFailed(_)
+        ));
+        assert_eq!(calendar.days[1].parts[0], PartStatus::NoInput);
+        assert_eq!(calendar.stars(), 0);
+    }
+
+    #[test]
+    fn failures() {
+        let solutions = [
+            RegisteredSolution::new::<Both>(),
+            RegisteredSolution::new::<Panics>(),
+        ];
+        let calendar = Calendar::collect(&solutions, |_| Ok("1".to_owned())).unwrap();
+        assert_eq!(
+            calendar.days[2].parts,
+            [
+                PartStatus::Answered(Answer::Text("fine".to_owned())),
+                PartStatus::Failed("out of bytes".to_owned())
+            ]
+        );
+        assert_eq!(calendar.stars(), 3);
+        let report = calendar.to_string();
+        assert!(report.contains("\n03   *            failed\n"));
+        assert!(report.contains("\nday 03 part 2: failed: out of bytes\n"));
+
+        let calendar = Calendar::collect(&solutions, |_| Ok("0".to_owned())).unwrap();
+        assert_eq!(
+            calendar.days[0].part(Part::Two),
+            &PartStatus::Failed("nothing to double".to_owned())
+        );
+        assert_eq!(calendar.stars(), 2);
+
+        let denied = Calendar::collect(&solutions, |day| {
+            Err(InputError::Io {
+                day,
+                path: "day01.txt".into(),
+                source: io::ErrorKind::PermissionDenied.into(),
+            })
+        });
+        assert!(matches!(denied, Err(InputError::Io { day: 1, .. })));
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Day01;
+
+fn extract_nums(list: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut first = vec![];
+    let mut second = vec![];
+    for line in parse::lines(Day01::DAY, list) {
+        let mut iter = line.text.split_whitespace();
+        let a = line.parse(line.next(&mut iter, "a number")?, "a number")?;
+        first.push(a);
+        let b = line.parse(line.next(&mut iter, "a number")?, "a number")?;
+        second.push(b);
+    }
+    Ok((first, second))
+}
+
+fn part1(first: &mut [u32], second: &mut [u32]) -> u32 {
+    first.sort();
+    second.sort();
+
+    first
+        .iter()
+        .zip(second.iter())
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum()
+}
+
+fn part2(first: &[u32], second: &[u32]) -> u32 {
+    let mut frequency_map = HashMap::new();
+
+    for i in second {
+        let freq = frequency_map.entry(*i).or_insert(0);
+        *freq += 1;
+    }
+
+    first
+        .iter()
+        .map(|x| x * frequency_map.get(x).unwrap_or(&0))
+        .sum()
+}
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        extract_nums(input)
+    }
+
+    fn part1((first, second): &Self::Input) -> Answer {
+        part1(&mut first.clone(), &mut second.clone()).into()
+    }
+
+    fn part2((first, second): &Self::Input) -> Answer {
+        part2(first, second).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day01: example);
+}
@@ -1,22 +1,37 @@
-fn parse_reports(text: &str) -> Vec<Vec<u32>> {
-    text.lines()
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn parse_reports(text: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse::lines(Day02::DAY, text)
         .map(|line| {
-            line.split_whitespace()
-                .map(|x| x.parse().unwrap())
+            line.text
+                .split_whitespace()
+                .map(|x| line.parse(x, "a number"))
                 .collect()
         })
         .collect()
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day02.txt").unwrap();
-    let mut reports = parse_reports(&input);
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
 
-    let part1_result = part1(&reports);
-    println!("part 1 result: {part1_result}");
+    fn part1(reports: &Self::Input) -> Answer {
+        part1(reports).into()
+    }
 
-    let part2_result = part2(&mut reports);
-    println!("part 1 result: {part2_result}");
+    fn part2(reports: &Self::Input) -> Answer {
+        part2(&mut reports.clone()).into()
+    }
 }
 
 fn valid_report(report: &[u32]) -> Result<(), usize> {
@@ -79,3 +94,10 @@ fn part2(reports: &mut [Vec<u32>]) -> usize {
         .filter(|b| *b)
         .count()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day02: example);
+}
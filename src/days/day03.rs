@@ -1,23 +1,32 @@
 use regex::Regex;
 
+use crate::{
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Instruction {
+pub enum Instruction {
     Mul { a: u32, b: u32 },
     Do,
     Dont,
 }
 
-fn extract_instructions(s: &str) -> Vec<Instruction> {
+fn extract_instructions(s: &str) -> Result<Vec<Instruction>, ParseError> {
     let re = Regex::new(r#"mul\((\d+),(\d+)\)|do\(\)|don't\(\)"#).unwrap();
-    re.find_iter(s)
-        .map(|m| {
-            let match_str = m.as_str();
-            match &match_str[0..4] {
+    re.captures_iter(s)
+        .map(|captures| {
+            let match_str = captures.get(0).unwrap().as_str();
+            let parse_operand = |i| {
+                let operand = captures.get(i).unwrap().as_str();
+                operand
+                    .parse()
+                    .map_err(|_| ParseError::in_input(Day03::DAY, s, operand, "a 32-bit number"))
+            };
+            Ok(match &match_str[0..4] {
                 "mul(" => {
-                    let mul_input = match_str.replace("mul(", "").replace(")", "");
-                    let mut iter = mul_input.split(",");
-                    let a = iter.next().unwrap().parse().unwrap();
-                    let b = iter.next().unwrap().parse().unwrap();
+                    let a = parse_operand(1)?;
+                    let b = parse_operand(2)?;
                     Instruction::Mul { a, b }
                 }
                 "don'" => Instruction::Dont,
@@ -25,7 +34,7 @@ fn extract_instructions(s: &str) -> Vec<Instruction> {
                 _ => {
                     unreachable!("unknown instruction: {match_str}")
                 }
-            }
+            })
         })
         .collect()
 }
@@ -58,23 +67,36 @@ fn part2(instructions: &[Instruction]) -> u32 {
         .0
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day03.txt").unwrap();
-    let instructions = extract_instructions(&input);
-    let part1_res = part1(&instructions);
-    println!("part 1: {part1_res}");
-    let part2_res = part2(&instructions);
-    println!("part 2: {part2_res}");
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        extract_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        part1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        part2(instructions).into()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    crate::example_tests!(Day03: conditionals, mul);
+
     #[test]
     fn test_extract_instructions() {
         let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        let instructions = extract_instructions(input);
+        let instructions = extract_instructions(input).unwrap();
         assert_eq!(
             instructions,
             vec![
@@ -0,0 +1,106 @@
+use crate::{
+    direction::Direction8,
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+fn part1(grid: &Grid<char>) -> usize {
+    let mut ret = 0;
+    let to_find = ['X', 'M', 'A', 'S'];
+
+    for line in 0..grid.dimension().y {
+        for x in 0..grid.dimension().x {
+            let idx = uidx2(line, x);
+            ret += find_targets(idx, grid, &to_find);
+        }
+    }
+
+    ret
+}
+
+fn find_targets(idx: UIndex2, grid: &Grid<char>, to_find: &[char]) -> usize {
+    if grid[idx] != to_find[0] {
+        return 0;
+    }
+    let mut ret = 0;
+
+    'next_direction: for d in Direction8::ALL {
+        let mut idx = idx;
+        for i in 0..to_find.len() - 1 {
+            if let Some(target) = d.get_neighbor(idx, grid) {
+                idx = target;
+                if grid[idx] == to_find[i + 1] {
+                    continue;
+                }
+            }
+            continue 'next_direction;
+        }
+        ret += 1;
+    }
+
+    ret
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    let mut ret = 0;
+
+    for line in 0..grid.dimension().y {
+        for x in 0..grid.dimension().x {
+            let idx = uidx2(line, x);
+            ret += if is_xmas(idx, grid) { 1 } else { 0 };
+        }
+    }
+
+    ret
+}
+
+fn is_xmas(idx: UIndex2, grid: &Grid<char>) -> bool {
+    if grid[idx] != 'A' {
+        return false;
+    }
+
+    for d in [Direction8::UpLeft, Direction8::UpRight] {
+        let a = d.get_neighbor(idx, grid);
+        let b = d.invert().get_neighbor(idx, grid);
+
+        let (Some(a), Some(b)) = (a, b) else {
+            return false;
+        };
+
+        let valid = grid[a] == 'M' && grid[b] == 'S' || grid[a] == 'S' && grid[b] == 'M';
+        if !valid {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        part2(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day04: example);
+}
@@ -1,35 +1,48 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug)]
-struct Rules {
+pub struct Rules {
     dependencies: HashMap<u32, HashSet<u32>>,
     updates: Vec<Vec<u32>>,
 }
 
-fn parse(text: &str) -> Rules {
+fn parse(text: &str) -> Result<Rules, ParseError> {
     let mut rules = Rules {
         dependencies: HashMap::new(),
         updates: vec![],
     };
 
     let mut parsing_dependencies = true;
-    for line in text.lines() {
-        if line.trim() == "" {
+    for line in parse::lines(Day05::DAY, text) {
+        if line.text.trim() == "" {
             parsing_dependencies = false;
             continue;
         }
         if parsing_dependencies {
-            let mut nums = line.split("|").map(|s| s.parse().unwrap());
-            let a = nums.next().unwrap();
-            let b = nums.next().unwrap();
+            let dependency = line
+                .text
+                .split_once("|")
+                .and_then(|(a, b)| Some((a.parse().ok()?, b.parse().ok()?)));
+            let Some((a, b)) = dependency else {
+                return Err(line.error("`a|b`"));
+            };
             rules.dependencies.entry(a).or_default().insert(b);
         } else {
-            let nums = line.split(",").map(|s| s.parse().unwrap()).collect();
+            let nums = line
+                .text
+                .split(",")
+                .map(|s| line.parse(s, "a page number"))
+                .collect::<Result<_, _>>()?;
             rules.updates.push(nums);
         }
     }
 
-    rules
+    Ok(rules)
 }
 
 fn update_valid(rule: &Rules, update: &[u32]) -> Result<(), (usize, usize)> {
@@ -73,11 +86,29 @@ fn part2(rules: &Rules) -> u32 {
     ret
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day05.txt").unwrap();
-    let rules = parse(&input);
-    let part1_res = part1(&rules);
-    println!("part 1 result: {part1_res}");
-    let part2_res = part2(&rules);
-    println!("part 2 result: {part2_res}");
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Rules;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rules: &Self::Input) -> Answer {
+        part1(rules).into()
+    }
+
+    fn part2(rules: &Self::Input) -> Answer {
+        part2(rules).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day05: example);
 }
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use crate::{
+    direction::Direction,
+    grid::Grid,
+    index2::UIndex2,
+    parallel,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Blocked,
+}
+#[derive(Debug, Copy, Clone)]
+pub struct Guard {
+    direction: Direction,
+    position: UIndex2,
+}
+
+impl Guard {
+    fn next_position(&self, grid: &Grid<Tile>) -> Option<UIndex2> {
+        self.direction.get_neighbor(self.position, grid)
+    }
+}
+
+fn parse(text: &str) -> Result<(Grid<Tile>, Guard), ParseError> {
+    let mut position = None;
+
+    let grid = Grid::from_str_with(Day06::DAY, text, |c, idx| match c {
+        '#' => Ok(Tile::Blocked),
+        '.' => Ok(Tile::Empty),
+        '^' => {
+            position = Some(idx);
+            Ok(Tile::Empty)
+        }
+        _ => Err("one of `#`, `.`, `^`"),
+    })?;
+    let position = position.ok_or_else(|| ParseError::at_end(Day06::DAY, text, "a guard `^`"))?;
+
+    Ok((
+        grid,
+        Guard {
+            direction: Direction::Up,
+            position,
+        },
+    ))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = (Grid<Tile>, Guard);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((grid, guard): &Self::Input) -> Answer {
+        part1(grid, *guard).into()
+    }
+
+    fn part2((grid, guard): &Self::Input) -> Answer {
+        part2(grid, *guard).into()
+    }
+}
+
+#[derive(Debug)]
+struct WalkResult {
+    seen: HashSet<(UIndex2, Direction)>,
+    looped: bool,
+}
+
+fn walk(grid: &Grid<Tile>, guard: Guard) -> WalkResult {
+    walk_with_obstacle(grid, guard, None)
+}
+
+/// Walks as if `obstacle` were blocked as well.
+fn walk_with_obstacle(
+    grid: &Grid<Tile>,
+    mut guard: Guard,
+    obstacle: Option<UIndex2>,
+) -> WalkResult {
+    let mut seen: HashSet<(UIndex2, Direction)> = HashSet::new();
+
+    loop {
+        if seen.contains(&(guard.position, guard.direction)) {
+            return WalkResult { seen, looped: true };
+        }
+        seen.insert((guard.position, guard.direction));
+
+        let Some(next_position) = guard.next_position(grid) else {
+            break;
+        };
+        if grid[next_position] == Tile::Blocked || Some(next_position) == obstacle {
+            guard.direction = guard.direction.turn_clockwise();
+        } else {
+            guard.position = next_position;
+        }
+    }
+    WalkResult {
+        seen,
+        looped: false,
+    }
+}
+
+fn part1(grid: &Grid<Tile>, guard: Guard) -> u32 {
+    let WalkResult { seen, .. } = walk(grid, guard);
+    // count all seen squares, but ignore multiple visits from different directions
+    let all_visited = seen
+        .iter()
+        .map(|(position, _)| *position)
+        .collect::<HashSet<_>>();
+
+    all_visited.len() as _
+}
+
+/// Draws the grid with every position visited by the guard marked as `X`.
+pub fn render_walk(grid: &Grid<Tile>, guard: Guard) -> String {
+    let WalkResult { seen, .. } = walk(grid, guard);
+    grid.render(|_, tile| match tile {
+        Tile::Empty => '.',
+        Tile::Blocked => '#',
+    })
+    .overlay(seen.into_iter().map(|(position, _)| position), 'X')
+    .to_string()
+}
+
+fn part2(grid: &Grid<Tile>, guard: Guard) -> u32 {
+    let WalkResult { seen, .. } = walk(grid, guard);
+
+    // block each visited position and check for loops
+    let mut potential_blocks = seen
+        .iter()
+        .map(|(position, _)| *position)
+        .collect::<HashSet<_>>();
+    potential_blocks.remove(&guard.position);
+    let potential_blocks = potential_blocks.into_iter().collect::<Vec<_>>();
+
+    parallel::sum(&potential_blocks, |position| {
+        walk_with_obstacle(grid, guard, Some(*position)).looped as u64
+    }) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day06: example);
+
+    #[test]
+    fn test_render_walk() {
+        let example = crate::fixture::Fixture::load(Day06::DAY, "example").unwrap();
+        let (grid, guard) = parse(&example.input).unwrap();
+        assert_eq!(
+            render_walk(&grid, guard),
+            "\
+....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XXXXXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X.."
+        );
+    }
+}
@@ -1,5 +1,11 @@
+use crate::{
+    parallel,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug)]
-struct Combination {
+pub struct Combination {
     total: u64,
     operands: Vec<u64>,
 }
@@ -11,19 +17,21 @@ enum Operation {
     Concat,
 }
 
-fn parse_combinations(input: &str) -> Vec<Combination> {
-    input
-        .lines()
+fn parse_combinations(input: &str) -> Result<Vec<Combination>, ParseError> {
+    parse::lines(Day07::DAY, input)
         .map(|line| {
-            let mut iter = line.split(":");
-            let total = iter.next().unwrap().parse().unwrap();
-            let operands = iter
-                .next()
-                .unwrap()
+            let Some((total, operands)) = line.text.split_once(":") else {
+                return Err(line.error("`total: operands...`"));
+            };
+            let total = line.parse(total, "a number")?;
+            let operands = operands
                 .split_whitespace()
-                .map(|s| s.parse().unwrap())
-                .collect();
-            Combination { total, operands }
+                .map(|s| line.parse(s, "a number"))
+                .collect::<Result<Vec<_>, _>>()?;
+            if operands.is_empty() {
+                return Err(line.error("at least one operand"));
+            }
+            Ok(Combination { total, operands })
         })
         .collect()
 }
@@ -38,7 +46,7 @@ fn check_combination(combination: &Combination, base: u64) -> bool {
         let mut bitset = bitset;
         for i in 0..num_operations {
             let digit = bitset % base;
-            bitset = bitset / base;
+            bitset /= base;
 
             let op = match digit {
                 0 => Operation::Add,
@@ -68,20 +76,38 @@ fn check_combination(combination: &Combination, base: u64) -> bool {
 }
 
 fn compute(combinations: &[Combination], base: u64) -> u64 {
-    combinations
-        .iter()
-        .filter(|c| check_combination(c, base))
-        .map(|c| c.total)
-        .sum()
+    parallel::sum(combinations, |c| {
+        if check_combination(c, base) {
+            c.total
+        } else {
+            0
+        }
+    })
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day07.txt").unwrap();
-    let combinations = parse_combinations(&input);
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<Combination>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_combinations(input)
+    }
+
+    fn part1(combinations: &Self::Input) -> Answer {
+        compute(combinations, 2).into()
+    }
+
+    fn part2(combinations: &Self::Input) -> Answer {
+        compute(combinations, 3).into()
+    }
+}
 
-    let part1_res = compute(&combinations, 2);
-    println!("part 1 result: {part1_res}");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let part2_res = compute(&combinations, 3);
-    println!("part 2 result: {part2_res}");
+    crate::example_tests!(Day07: example);
 }
@@ -0,0 +1,81 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+fn positions_per_node(grid: &Grid<char>) -> HashMap<char, Vec<UIndex2>> {
+    let mut positions_per_node = HashMap::new();
+
+    for x in 0..grid.dimension().x {
+        for y in 0..grid.dimension().y {
+            let idx = uidx2(x, y);
+            let c = grid[idx];
+            if c == '.' {
+                continue;
+            }
+
+            let positions = positions_per_node.entry(c).or_insert(Vec::new());
+            positions.push(idx);
+        }
+    }
+    positions_per_node
+}
+
+fn calculate_antinodes(grid: &Grid<char>, only_once: bool) -> usize {
+    let positions_per_node = positions_per_node(grid);
+
+    let mut anti_nodes = HashSet::new();
+
+    for (_, positions) in positions_per_node {
+        for (i, pos_a) in positions.iter().enumerate() {
+            for pos_b in positions[i + 1..].iter() {
+                let diff = pos_b.to_index2() - pos_a.to_index2();
+                for (start, step) in [(*pos_b, diff), (*pos_a, -1 * diff)] {
+                    let line = grid.ray(start, step).map(|(idx, _)| idx);
+                    if only_once {
+                        // part 1: only two anti-nodes per pair
+                        anti_nodes.extend(line.take(1));
+                    } else {
+                        // part 2: the entire line inside the grid, including the antennas themselves.
+                        // in-between points are not necessary (i.e. if diff was (2, 2), we don't need to consider the normalized (1, 1) instead).
+                        anti_nodes.insert(start);
+                        anti_nodes.extend(line);
+                    }
+                }
+            }
+        }
+    }
+
+    anti_nodes.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        calculate_antinodes(grid, true).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        calculate_antinodes(grid, false).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day08: example);
+}
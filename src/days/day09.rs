@@ -1,3 +1,8 @@
+use crate::{
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum BlockType {
     File { id: u32 },
@@ -5,7 +10,7 @@ enum BlockType {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Block {
+pub struct Block {
     block_type: BlockType,
     size: u32,
 }
@@ -126,10 +131,9 @@ fn part2(blocks: &[Block]) -> u64 {
 
             {
                 // update the checksum (return value)
-                let mut current_block = free_space.starting_block_idx;
-                for _ in 0..file_block.size {
+                let start = free_space.starting_block_idx;
+                for current_block in start..start + file_block.size {
                     ret += file_block.file_id as u64 * current_block as u64;
-                    current_block += 1;
                 }
             }
 
@@ -143,35 +147,61 @@ fn part2(blocks: &[Block]) -> u64 {
     // all blocks have been moved -- now we just need to handle the blocks that weren't moved.
     // since moved blocks have had their size set to 0, they will be effectively ignored in the following computation.
     for file_block in &file_blocks {
-        let mut current_block = file_block.starting_block_idx;
-        for _ in 0..file_block.size {
+        let start = file_block.starting_block_idx;
+        for current_block in start..start + file_block.size {
             ret += file_block.file_id as u64 * current_block as u64;
-            current_block += 1;
         }
     }
 
     ret
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day09.txt").unwrap();
-    let blocks = input
+fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+    let disk_map = input.trim();
+    disk_map
         .char_indices()
-        .map(|(i, c)| Block {
-            size: c.to_string().parse().unwrap(),
-            block_type: if i % 2 == 0 {
-                BlockType::File {
-                    id: (i as u32 + 1) / 2,
-                }
-            } else {
-                BlockType::Empty
-            },
+        .map(|(i, c)| {
+            let size = c.to_digit(10).ok_or_else(|| {
+                let found = &disk_map[i..i + c.len_utf8()];
+                ParseError::in_input(Day09::DAY, input, found, "a digit")
+            })?;
+            Ok(Block {
+                size,
+                block_type: if i % 2 == 0 {
+                    BlockType::File {
+                        id: (i as u32).div_ceil(2),
+                    }
+                } else {
+                    BlockType::Empty
+                },
+            })
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<Block>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(blocks: &Self::Input) -> Answer {
+        part1(blocks).into()
+    }
+
+    fn part2(blocks: &Self::Input) -> Answer {
+        part2(blocks).into()
+    }
+}
 
-    let part1_res = part1(&blocks);
-    println!("part 1 result: {part1_res}");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let part2_res = part2(&blocks);
-    println!("part 2 result: {part2_res}");
+    crate::example_tests!(Day09: example);
 }
@@ -0,0 +1,92 @@
+use crate::{
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+    parse::ParseError,
+    search,
+    solution::{Answer, Solution},
+};
+
+fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::from_str_with(Day10::DAY, input, |c, _| {
+        c.to_digit(10).ok_or("a height digit")
+    })
+}
+
+/// Neighbours exactly one step higher than `idx`.
+fn uphill(idx: UIndex2, grid: &Grid<u32>) -> impl Iterator<Item = UIndex2> + use<'_> {
+    grid.neighbors4(idx)
+        .filter(move |(_, height)| **height == grid[idx] + 1)
+        .map(|(neighbor_idx, _)| neighbor_idx)
+}
+
+fn trailheads(grid: &Grid<u32>) -> Vec<UIndex2> {
+    let mut starts = vec![];
+    for y in 0..grid.dimension().y {
+        for x in 0..grid.dimension().x {
+            let idx = uidx2(x, y);
+            if grid[idx] == 0 {
+                starts.push(idx);
+            }
+        }
+    }
+    starts
+}
+
+/// The number of distinct height-9 positions reachable from each trailhead.
+fn trails_score(grid: &Grid<u32>) -> u32 {
+    trailheads(grid)
+        .into_iter()
+        .map(|start| {
+            search::bfs(start, |&idx| uphill(idx, grid), |_| false)
+                .distances
+                .into_keys()
+                .filter(|idx| grid[*idx] == 9)
+                .count() as u32
+        })
+        .sum()
+}
+
+/// The number of distinct trails from each trailhead.
+fn trails_rating(grid: &Grid<u32>) -> u64 {
+    trailheads(grid)
+        .into_iter()
+        .map(|start| {
+            // every trail has exactly 9 steps, so all of them are shortest paths
+            search::dijkstra_all(
+                start,
+                |&idx| uphill(idx, grid).map(|n| (n, 1)),
+                |idx| grid[*idx] == 9,
+            )
+            .count_paths()
+            .to_u64()
+            .unwrap()
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        trails_score(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        trails_rating(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day10: example);
+}
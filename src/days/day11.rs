@@ -1,5 +1,10 @@
 use std::collections::HashMap;
 
+use crate::{
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
 fn blink(nums: &[u32], num_iterations: u32) -> u64 {
     let mut current = HashMap::new();
 
@@ -37,16 +42,39 @@ fn blink(nums: &[u32], num_iterations: u32) -> u64 {
     current.values().sum()
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day11.txt").unwrap();
-    let nums = input
+fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
         .split_whitespace()
-        .map(|num| num.parse().unwrap())
-        .collect::<Vec<_>>();
+        .map(|num| {
+            num.parse()
+                .map_err(|_| ParseError::in_input(Day11::DAY, input, num, "a number"))
+        })
+        .collect()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(nums: &Self::Input) -> Answer {
+        blink(nums, 25).into()
+    }
+
+    fn part2(nums: &Self::Input) -> Answer {
+        blink(nums, 75).into()
+    }
+}
 
-    let part1_res = blink(&nums, 25);
-    println!("part 1: {part1_res}");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let part2_res = blink(&nums, 75);
-    println!("part 2: {part2_res}");
+    crate::example_tests!(Day11: example);
 }
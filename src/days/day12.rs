@@ -1,25 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc2024::{
+use crate::{
     direction::Direction,
     grid::Grid,
     index2::{uidx2, Index2, UIndex2},
+    parse::ParseError,
+    solution::{Answer, Solution},
 };
 
-fn parse(input: &str) -> Grid<char> {
-    let num_lines = input.lines().count();
-    let num_per_line = input.lines().next().unwrap().len();
-    let mut grid = Grid::new_with_default(uidx2(num_per_line as _, num_lines as _));
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.char_indices() {
-            grid[uidx2(x as _, y as _)] = c;
-        }
-    }
-
-    grid
-}
-
 fn calculate_area_costs(grid: &Grid<char>) -> (u32, u32) {
     let mut part1 = 0;
     let mut part2 = 0;
@@ -60,7 +48,7 @@ fn area_costs(start_idx: UIndex2, grid: &Grid<char>, visited: &mut Grid<bool>) -
 
     while let Some(idx) = next.pop() {
         area += 1;
-        for direction in Direction::ALL.into_iter() {
+        for direction in Direction::ALL.iter().copied() {
             if let Some(neighbor) = direction.get_neighbor(idx, grid) {
                 if grid[neighbor] == letter {
                     if !visited[neighbor] {
@@ -131,7 +119,7 @@ fn area_costs(start_idx: UIndex2, grid: &Grid<char>, visited: &mut Grid<bool>) -
 
         visited.insert(start_vertex);
         let directions = &edges_per_lattice_index[&start_vertex];
-        let mut current_direction = directions.iter().next().unwrap().clone();
+        let mut current_direction = *directions.iter().next().unwrap();
         let mut current_vertex = start_vertex + current_direction.to_index2();
 
         loop {
@@ -196,11 +184,29 @@ fn area_costs(start_idx: UIndex2, grid: &Grid<char>, visited: &mut Grid<bool>) -
     (num_fences * area, num_sides * area)
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day12.txt").unwrap();
-    let grid = parse(&input);
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        calculate_area_costs(grid).0.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        calculate_area_costs(grid).1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let (part1_res, part2_res) = calculate_area_costs(&grid);
-    println!("part 1 result: {part1_res}");
-    println!("part 2 result: {part2_res}");
+    crate::example_tests!(Day12: example);
 }
@@ -0,0 +1,309 @@
+use crate::{
+    index2::{u64idx2, U64Index2},
+    parallel,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Configuration {
+    a: U64Index2,
+    b: U64Index2,
+    target: U64Index2,
+}
+
+fn parse(input: &str) -> Result<Vec<Configuration>, ParseError> {
+    let mut ret = vec![];
+
+    let mut current = Configuration {
+        a: U64Index2::zero(),
+        b: U64Index2::zero(),
+        target: U64Index2::zero(),
+    };
+    let re = Regex::new(r#"X[+=](\d+),\sY[+=](\d+)"#).unwrap();
+    for line in parse::lines(Day13::DAY, input) {
+        let text = line.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let Some((_, [x, y])) = re.captures(text).map(|c| c.extract()) else {
+            return Err(line.error("`X+<x>, Y+<y>` or `X=<x>, Y=<y>`"));
+        };
+        let x = line.parse(x, "a number")?;
+        let y = line.parse(y, "a number")?;
+
+        if text.contains("A") {
+            current.a = u64idx2(x, y);
+        } else if text.contains("B") {
+            current.b = u64idx2(x, y);
+        } else if text.contains("Prize") {
+            current.target = u64idx2(x, y);
+            ret.push(current);
+        } else {
+            return Err(line.error("`Button A`, `Button B` or `Prize`"));
+        }
+    }
+
+    Ok(ret)
+}
+
+/// How often each button is pressed to win a prize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Presses {
+    pub a: u64,
+    pub b: u64,
+}
+
+/// The rules for winning prizes: what each button press costs and how often a button may be
+/// pressed at most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClawMachine {
+    cost_a: u64,
+    cost_b: u64,
+    press_limit: Option<u64>,
+}
+
+impl Default for ClawMachine {
+    /// Pressing A costs 3 tokens, pressing B 1 token, without a press limit.
+    fn default() -> Self {
+        Self {
+            cost_a: 3,
+            cost_b: 1,
+            press_limit: None,
+        }
+    }
+}
+
+impl ClawMachine {
+    pub fn with_costs(self, cost_a: u64, cost_b: u64) -> Self {
+        Self {
+            cost_a,
+            cost_b,
+            ..self
+        }
+    }
+
+    /// Limits how often each button may be pressed.
+    pub fn with_press_limit(self, press_limit: u64) -> Self {
+        Self {
+            press_limit: Some(press_limit),
+            ..self
+        }
+    }
+
+    pub fn cost(&self, presses: Presses) -> u64 {
+        presses.a * self.cost_a + presses.b * self.cost_b
+    }
+
+    /// The cheapest way to reach the prize, or `None` if it can't be reached.
+    pub fn solve(&self, configuration: &Configuration) -> Option<Presses> {
+        let [ax, ay, bx, by, tx, ty] = [
+            configuration.a.x,
+            configuration.a.y,
+            configuration.b.x,
+            configuration.b.y,
+            configuration.target.x,
+            configuration.target.y,
+        ]
+        .map(|v| v as i128);
+
+        let determinant = ax * by - ay * bx;
+        let (a, b) = if determinant != 0 {
+            // Cramer's rule, the only solution if it's integral
+            let a_numerator = tx * by - ty * bx;
+            let b_numerator = ax * ty - ay * tx;
+            if a_numerator % determinant != 0 || b_numerator % determinant != 0 {
+                return None;
+            }
+            (a_numerator / determinant, b_numerator / determinant)
+        } else {
+            // both buttons move along the same line, which the prize has to be on as well
+            if ax * ty - ay * tx != 0 || bx * ty - by * tx != 0 {
+                return None;
+            }
+            // project onto an axis the line isn't perpendicular to
+            if ax != 0 || bx != 0 {
+                self.solve_line(ax, bx, tx)?
+            } else {
+                self.solve_line(ay, by, ty)?
+            }
+        };
+
+        let limit = self.press_limit.map_or(i128::MAX, |limit| limit as i128);
+        if !(0..=limit).contains(&a) || !(0..=limit).contains(&b) {
+            return None;
+        }
+        let presses = Presses {
+            a: a as u64,
+            b: b as u64,
+        };
+        let reached = presses.a * configuration.a + presses.b * configuration.b;
+        (reached == configuration.target).then_some(presses)
+    }
+
+    /// The cheapest non-negative `(a, b)` within the press limit with `a * p + b * q = t`, for
+    /// non-negative `p`, `q` and `t`.
+    fn solve_line(&self, p: i128, q: i128, t: i128) -> Option<(i128, i128)> {
+        let limit = self.press_limit.map_or(i128::MAX, |limit| limit as i128);
+        match (p, q) {
+            (0, 0) => return (t == 0).then_some((0, 0)),
+            (0, q) => return (t % q == 0).then_some((0, t / q)),
+            (p, 0) => return (t % p == 0).then_some((t / p, 0)),
+            _ => {}
+        }
+
+        let (g, x, y) = extended_gcd(p, q);
+        if t % g != 0 {
+            return None;
+        }
+        // every solution is `(a0 + k * step_a, b0 - k * step_b)`
+        let (a0, b0) = (x * (t / g), y * (t / g));
+        let (step_a, step_b) = (q / g, p / g);
+
+        let max_a = i128::min(limit, t / p);
+        let max_b = i128::min(limit, t / q);
+        let k_min = i128::max(div_ceil(-a0, step_a), div_ceil(b0 - max_b, step_b));
+        let k_max = i128::min(div_floor(max_a - a0, step_a), div_floor(b0, step_b));
+        if k_min > k_max {
+            return None;
+        }
+
+        // the cost is linear in `k`, so one of the ends is cheapest
+        let slope = step_a * self.cost_a as i128 - step_b * self.cost_b as i128;
+        let k = if slope > 0 { k_min } else { k_max };
+        Some((a0 + k * step_a, b0 - k * step_b))
+    }
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`, for positive `a` and `b`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    a.div_euclid(b)
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -(-a).div_euclid(b)
+}
+
+fn total_cost(configs: &[Configuration], machine: ClawMachine) -> u64 {
+    parallel::sum(configs, |c| machine.solve(c).map_or(0, |p| machine.cost(p)))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Vec<Configuration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(configs: &Self::Input) -> Answer {
+        total_cost(configs, ClawMachine::default().with_press_limit(100)).into()
+    }
+
+    fn part2(configs: &Self::Input) -> Answer {
+        const OFFSET: u64 = 10000000000000;
+        let offset = u64idx2(OFFSET, OFFSET);
+
+        let configs = configs
+            .iter()
+            .map(|c| Configuration {
+                a: c.a,
+                b: c.b,
+                target: c.target + offset,
+            })
+            .collect::<Vec<_>>();
+        total_cost(&configs, ClawMachine::default()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day13: example);
+
+    fn configuration(a: (u64, u64), b: (u64, u64), target: (u64, u64)) -> Configuration {
+        Configuration {
+            a: u64idx2(a.0, a.1),
+            b: u64idx2(b.0, b.1),
+            target: u64idx2(target.0, target.1),
+        }
+    }
+
+    #[test]
+    fn test_unique_solution() {
+        let machine = ClawMachine::default();
+        let c = configuration((94, 34), (22, 67), (8400, 5400));
+        assert_eq!(machine.solve(&c), Some(Presses { a: 80, b: 40 }));
+        assert_eq!(machine.cost(Presses { a: 80, b: 40 }), 280);
+        assert_eq!(machine.with_press_limit(50).solve(&c), None);
+
+        // not integral
+        let c = configuration((26, 66), (67, 21), (12748, 12176));
+        assert_eq!(machine.solve(&c), None);
+
+        // integral, but needs a negative number of presses
+        let c = configuration((3, 1), (1, 2), (1, 7));
+        assert_eq!(machine.solve(&c), None);
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let machine = ClawMachine::default();
+        let c = configuration((1, 1), (2, 2), (10, 10));
+        assert_eq!(machine.solve(&c), Some(Presses { a: 0, b: 5 }));
+        assert_eq!(
+            machine.with_press_limit(4).solve(&c),
+            Some(Presses { a: 2, b: 4 })
+        );
+        assert_eq!(
+            machine.with_costs(1, 3).solve(&c),
+            Some(Presses { a: 10, b: 0 })
+        );
+        assert_eq!(machine.with_press_limit(3).solve(&c), None);
+
+        let c = configuration((4, 2), (6, 3), (14, 7));
+        assert_eq!(machine.solve(&c), Some(Presses { a: 2, b: 1 }));
+        // the prize isn't on the buttons' line
+        assert_eq!(machine.solve(&configuration((1, 1), (2, 2), (3, 4))), None);
+        // no combination adds up
+        assert_eq!(machine.solve(&configuration((2, 2), (4, 4), (5, 5))), None);
+        // a button that doesn't move the claw
+        let c = configuration((0, 0), (3, 3), (9, 9));
+        assert_eq!(machine.solve(&c), Some(Presses { a: 0, b: 3 }));
+        let c = configuration((0, 2), (0, 3), (0, 7));
+        assert_eq!(machine.solve(&c), Some(Presses { a: 2, b: 1 }));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let machine = ClawMachine::default().with_press_limit(6);
+        // every button with coordinates in `0..4`
+        for i in 0..256u64 {
+            let (ax, ay, bx, by) = (i & 3, (i >> 2) & 3, (i >> 4) & 3, (i >> 6) & 3);
+            for (tx, ty) in [(0, 0), (6, 6), (4, 8), (5, 7), (9, 3), (12, 0)] {
+                let c = configuration((ax, ay), (bx, by), (tx, ty));
+                let brute_force = (0..=6)
+                    .flat_map(|a| (0..=6).map(move |b| Presses { a, b }))
+                    .filter(|p| p.a * c.a + p.b * c.b == c.target)
+                    .map(|p| machine.cost(p))
+                    .min();
+                let solved = machine.solve(&c);
+                assert_eq!(solved.map(|p| machine.cost(p)), brute_force, "{c:?}");
+            }
+        }
+    }
+}
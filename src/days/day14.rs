@@ -0,0 +1,485 @@
+use std::num::NonZeroU32;
+
+use crate::{
+    grid::Grid,
+    index2::{uidx2, Index2, UIndex2},
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+};
+
+const MAP_SIZE: UIndex2 = UIndex2 { x: 101, y: 103 };
+
+#[derive(Debug)]
+pub struct Robot {
+    position: Index2,
+    velocity: Index2,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    robots: Vec<Robot>,
+    /// The room's size, which isn't part of the input.
+    map_size: UIndex2,
+}
+
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let robots = parse::lines(Day14::DAY, input)
+        .map(|line| {
+            let mut split = line.text.split_whitespace();
+
+            fn extract<'a>(line: &Line<'a>, text: &'a str) -> Result<Index2, ParseError> {
+                let Some((x, y)) = text.split_once("=").and_then(|(_, v)| v.split_once(",")) else {
+                    return Err(line.error_at(text, "`<name>=<x>,<y>`"));
+                };
+                Ok(Index2::new(
+                    line.parse(x, "a number")?,
+                    line.parse(y, "a number")?,
+                ))
+            }
+
+            let position = extract(&line, line.next(&mut split, "`p=<x>,<y>`")?)?;
+            let velocity = extract(&line, line.next(&mut split, "`v=<x>,<y>`")?)?;
+            Ok(Robot { position, velocity })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Input {
+        robots,
+        map_size: MAP_SIZE,
+    })
+}
+
+fn robot_position_after_iterations(
+    robot: &Robot,
+    num_iterations: u32,
+    map_size: UIndex2,
+) -> UIndex2 {
+    let raw_position = robot.position + num_iterations as i32 * robot.velocity;
+    let x = ((raw_position.x % map_size.x as i32) + map_size.x as i32) as u32 % map_size.x;
+    let y = ((raw_position.y % map_size.y as i32) + map_size.y as i32) as u32 % map_size.y;
+    uidx2(x, y)
+}
+
+fn part1(robots: &[Robot], num_iterations: u32, map_size: UIndex2) -> u32 {
+    safety_factor(
+        &Simulation::new(robots, map_size)
+            .snapshot(num_iterations)
+            .grid,
+    )
+}
+
+/// Product of the number of robots in each quadrant, ignoring the middle row and column.
+fn safety_factor(grid: &Grid<u32>) -> u32 {
+    let map_size = grid.dimension();
+
+    // quadrants
+    let half_width = map_size.x / 2;
+    let half_height = map_size.y / 2;
+
+    let left_width_start = 0;
+    let right_width_start = map_size.x - half_width;
+    let top_height_start = 0;
+    let bottom_height_start = map_size.y - half_height;
+    let quadrants = [
+        // top left
+        (
+            uidx2(left_width_start, top_height_start),
+            uidx2(half_width, half_height),
+        ),
+        // bottom left
+        (
+            uidx2(left_width_start, bottom_height_start),
+            uidx2(half_width, map_size.y),
+        ),
+        // top right
+        (
+            uidx2(right_width_start, top_height_start),
+            uidx2(map_size.x, half_height),
+        ),
+        // bottom right
+        (
+            uidx2(right_width_start, bottom_height_start),
+            uidx2(map_size.x, map_size.y),
+        ),
+    ];
+
+    let mut robots_per_quadrant = [0, 0, 0, 0];
+    for (quadrant, (start, end)) in quadrants.iter().enumerate() {
+        for x in start.x..end.x {
+            for y in start.y..end.y {
+                let idx = uidx2(x, y);
+                robots_per_quadrant[quadrant] += grid[idx];
+            }
+        }
+    }
+
+    robots_per_quadrant
+        .iter()
+        .cloned()
+        .reduce(|a, b| a * b)
+        .unwrap()
+}
+
+/// The robots' positions after some number of seconds.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub seconds: u32,
+    pub positions: Vec<UIndex2>,
+    /// Number of robots per tile.
+    pub grid: Grid<u32>,
+}
+
+impl Snapshot {
+    /// Occupied tiles as digits (capped at 9), empty ones as `.`.
+    pub fn render(&self) -> String {
+        self.grid
+            .render(|_, value| match value {
+                0 => '.',
+                _ => char::from_digit(u32::min(*value, 9), 10).unwrap(),
+            })
+            .to_string()
+    }
+}
+
+/// Scores how structured a snapshot looks. Lower scores are more anomalous, so the picture the
+/// robots form is expected at the lowest score.
+pub trait AnomalyScorer {
+    fn score(&self, snapshot: &Snapshot) -> f64;
+}
+
+/// The longest horizontal run of occupied tiles, negated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RowRun;
+
+impl AnomalyScorer for RowRun {
+    fn score(&self, snapshot: &Snapshot) -> f64 {
+        let dimension = snapshot.grid.dimension();
+        let mut longest = 0;
+        for y in 0..dimension.y {
+            let mut run = 0;
+            for x in 0..dimension.x {
+                if snapshot.grid[uidx2(x, y)] > 0 {
+                    run += 1;
+                    longest = u32::max(longest, run);
+                } else {
+                    run = 0;
+                }
+            }
+        }
+        -(longest as f64)
+    }
+}
+
+/// Part 1's safety factor, which drops when most robots gather in one quadrant.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SafetyFactor;
+
+impl AnomalyScorer for SafetyFactor {
+    fn score(&self, snapshot: &Snapshot) -> f64 {
+        safety_factor(&snapshot.grid) as f64
+    }
+}
+
+/// Sum of the variances of the x and y coordinates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Variance;
+
+impl AnomalyScorer for Variance {
+    fn score(&self, snapshot: &Snapshot) -> f64 {
+        let xs = snapshot.positions.iter().map(|p| p.x);
+        let ys = snapshot.positions.iter().map(|p| p.y);
+        variance(xs) + variance(ys)
+    }
+}
+
+/// Shannon entropy (in bits) of the robot distribution over square blocks of tiles.
+#[derive(Debug, Clone, Copy)]
+pub struct Entropy {
+    block_size: NonZeroU32,
+}
+
+impl Entropy {
+    pub fn new(block_size: NonZeroU32) -> Self {
+        Self { block_size }
+    }
+}
+
+impl Default for Entropy {
+    fn default() -> Self {
+        Self::new(NonZeroU32::new(4).unwrap())
+    }
+}
+
+impl AnomalyScorer for Entropy {
+    fn score(&self, snapshot: &Snapshot) -> f64 {
+        let dimension = snapshot.grid.dimension();
+        let block_size = self.block_size.get();
+        let blocks = uidx2(
+            dimension.x.div_ceil(block_size),
+            dimension.y.div_ceil(block_size),
+        );
+        let mut counts = Grid::<u32>::new_with_default(blocks);
+        for position in &snapshot.positions {
+            counts[uidx2(position.x / block_size, position.y / block_size)] += 1;
+        }
+
+        let total = snapshot.positions.len() as f64;
+        counts
+            .iter()
+            .filter(|count| **count > 0)
+            .map(|count| {
+                let p = *count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+fn variance(values: impl Iterator<Item = u32> + Clone) -> f64 {
+    let (count, sum) = values
+        .clone()
+        .fold((0, 0.0), |(count, sum), v| (count + 1, sum + v as f64));
+    if count == 0 {
+        return 0.0;
+    }
+    let mean = sum / count as f64;
+    values.map(|v| (v as f64 - mean).powi(2)).sum::<f64>() / count as f64
+}
+
+/// The second at which a detector found the most anomalous arrangement, and its score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    pub seconds: u32,
+    pub score: f64,
+}
+
+/// The robots moving on a map that wraps around.
+#[derive(Debug, Clone, Copy)]
+pub struct Simulation<'a> {
+    robots: &'a [Robot],
+    map_size: UIndex2,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(robots: &'a [Robot], map_size: UIndex2) -> Self {
+        Self { robots, map_size }
+    }
+
+    /// After this many seconds, every robot is back at its starting position.
+    pub fn period(&self) -> u32 {
+        lcm(self.map_size.x as u64, self.map_size.y as u64) as u32
+    }
+
+    pub fn snapshot(&self, seconds: u32) -> Snapshot {
+        let mut grid = Grid::<u32>::new_with_default(self.map_size);
+        let positions = self
+            .robots
+            .iter()
+            .map(|robot| robot_position_after_iterations(robot, seconds, self.map_size))
+            .collect::<Vec<_>>();
+        for position in &positions {
+            grid[*position] += 1;
+        }
+        Snapshot {
+            seconds,
+            positions,
+            grid,
+        }
+    }
+
+    /// Scores every distinct arrangement and returns the lowest-scoring one, preferring the
+    /// earliest on ties.
+    pub fn detect(&self, scorer: &impl AnomalyScorer) -> Detection {
+        (0..self.period())
+            .map(|seconds| Detection {
+                seconds,
+                score: scorer.score(&self.snapshot(seconds)),
+            })
+            .reduce(|best, detection| {
+                if detection.score < best.score {
+                    detection
+                } else {
+                    best
+                }
+            })
+            .unwrap()
+    }
+
+    /// Finds the x coordinates' variance minimum within their period (the map width) and likewise
+    /// for y, then combines both with the Chinese remainder theorem. Only `width + height`
+    /// arrangements are looked at instead of `width * height`.
+    ///
+    /// Returns `None` if the two minima contradict each other, which can only happen if the map's
+    /// width and height aren't coprime.
+    pub fn detect_crt(&self) -> Option<Detection> {
+        let axis_minimum = |size: u32, axis: fn(Index2) -> i32| {
+            (0..size)
+                .map(|seconds| {
+                    let coordinates = self.robots.iter().map(move |robot| {
+                        let raw = axis(robot.position) as i64
+                            + seconds as i64 * axis(robot.velocity) as i64;
+                        raw.rem_euclid(size as i64) as u32
+                    });
+                    (seconds, variance(coordinates))
+                })
+                .reduce(|best, next| if next.1 < best.1 { next } else { best })
+                .unwrap()
+        };
+        let (x_seconds, _) = axis_minimum(self.map_size.x, |idx| idx.x);
+        let (y_seconds, _) = axis_minimum(self.map_size.y, |idx| idx.y);
+        let seconds = crt(
+            x_seconds as u64,
+            self.map_size.x as u64,
+            y_seconds as u64,
+            self.map_size.y as u64,
+        )? as u32;
+
+        Some(Detection {
+            seconds,
+            score: Variance.score(&self.snapshot(seconds)),
+        })
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+/// The smallest `t` with `t = a (mod m)` and `t = b (mod n)`, if there is one.
+fn crt(a: u64, m: u64, b: u64, n: u64) -> Option<u64> {
+    let g = gcd(m, n);
+    if a % g != b % g {
+        return None;
+    }
+    // `t = a + m * k` for the `k` with `m * k = b - a (mod n)`
+    let (m_reduced, n_reduced) = ((m / g) as i128, (n / g) as i128);
+    let difference = ((b as i128 - a as i128) / g as i128).rem_euclid(n_reduced);
+    let k = difference * mod_inverse(m_reduced % n_reduced, n_reduced) % n_reduced;
+    Some(a + m * k as u64)
+}
+
+/// The inverse of `a` modulo `n`, for coprime `a` and `n`.
+fn mod_inverse(a: i128, n: i128) -> i128 {
+    let (mut old_r, mut r) = (a, n);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    old_s.rem_euclid(n)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    const PARAMS: &'static [&'static str] = &["width", "height"];
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn set_param(input: &mut Self::Input, name: &str, value: u64) -> Result<(), String> {
+        let value = u32::try_from(value)
+            .ok()
+            .filter(|value| *value > 0)
+            .ok_or("the room must not be empty")?;
+        match name {
+            "width" => input.map_size.x = value,
+            "height" => input.map_size.y = value,
+            _ => return Err(format!("no parameter `{name}`")),
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(&input.robots, 100, input.map_size).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let simulation = Simulation::new(&input.robots, input.map_size);
+        let detection = simulation
+            .detect_crt()
+            .unwrap_or_else(|| simulation.detect(&RowRun));
+        detection.seconds.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day14: example);
+
+    /// Robots that form a filled square at `seconds`, plus some scattered ones.
+    fn hidden_square(map_size: UIndex2, seconds: u32) -> Vec<Robot> {
+        let mut state = 14u64;
+        let mut random = move |n: u32| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % n as u64) as i32
+        };
+
+        let mut targets = vec![];
+        for y in 10..20 {
+            for x in 5..15 {
+                targets.push(Index2::new(x, y));
+            }
+        }
+        for _ in 0..60 {
+            targets.push(Index2::new(random(map_size.x), random(map_size.y)));
+        }
+
+        let size = map_size.to_index2();
+        targets
+            .into_iter()
+            .map(|target| {
+                let velocity = Index2::new(random(21) - 10, random(21) - 10);
+                let start = target - seconds as i32 * velocity;
+                Robot {
+                    position: Index2::new(start.x.rem_euclid(size.x), start.y.rem_euclid(size.y)),
+                    velocity,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detectors() {
+        let map_size = uidx2(31, 37);
+        let robots = hidden_square(map_size, 500);
+        let simulation = Simulation::new(&robots, map_size);
+        assert_eq!(simulation.period(), 31 * 37);
+        assert_eq!(simulation.snapshot(500).grid[uidx2(5, 10)], 1);
+
+        assert_eq!(simulation.detect(&RowRun).seconds, 500);
+        assert_eq!(simulation.detect(&SafetyFactor).seconds, 500);
+        assert_eq!(simulation.detect(&Variance).seconds, 500);
+        assert_eq!(simulation.detect(&Entropy::default()).seconds, 500);
+        let small_blocks = Entropy::new(NonZeroU32::new(2).unwrap());
+        assert_eq!(simulation.detect(&small_blocks).seconds, 500);
+
+        let detection = simulation.detect_crt().unwrap();
+        assert_eq!(detection, simulation.detect(&Variance));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(2, 3, 3, 5), Some(8));
+        assert_eq!(crt(0, 101, 0, 103), Some(0));
+        assert_eq!(crt(1, 4, 3, 6), Some(9));
+        assert_eq!(crt(1, 4, 2, 6), None);
+    }
+}
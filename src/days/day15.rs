@@ -1,13 +1,18 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
 
-use aoc2024::{
+use crate::{
     direction::Direction,
     grid::Grid,
     index2::{uidx2, UIndex2},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
 };
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-enum Tile {
+pub enum Tile {
     #[default]
     Empty,
     Box,
@@ -17,92 +22,65 @@ enum Tile {
 }
 
 impl Tile {
-    fn is_box(self) -> bool {
-        matches!(self, Tile::Box | Tile::LeftBox | Tile::RightBox)
+    fn to_char(self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Box => 'O',
+            Tile::LeftBox => '[',
+            Tile::RightBox => ']',
+            Tile::Wall => '#',
+        }
     }
 }
 
 #[derive(Debug, Clone)]
-struct Input {
+pub struct Input {
     grid: Grid<Tile>,
     robot: UIndex2,
     movements: Vec<Direction>,
 }
 
-fn parse(input: &str) -> Input {
-    let width = input.lines().next().unwrap().len() as u32;
-    let height = input.lines().take_while(|l| !l.trim().is_empty()).count() as u32;
-    let size = uidx2(width, height);
-
-    let mut grid = Grid::new_with_default(size);
-    let mut robot = uidx2(0, 0);
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut lines = parse::lines(Day15::DAY, input);
+    let mut robot = None;
     let mut movements = vec![];
 
-    let mut parsing_grid = true;
-
-    for (y, line) in input.lines().enumerate() {
-        if line.trim().is_empty() {
-            parsing_grid = false;
-        } else if parsing_grid {
-            for (x, c) in line.char_indices() {
-                let idx = uidx2(x as u32, y as u32);
-                match c {
-                    '#' => {
-                        grid[idx] = Tile::Wall;
-                    }
-                    '.' => {
-                        grid[idx] = Tile::Empty;
-                    }
-                    'O' => {
-                        grid[idx] = Tile::Box;
-                    }
-                    '@' => {
-                        robot = idx;
-                    }
-                    _ => panic!("unknown tile {c}"),
-                }
-            }
-        } else {
-            for c in line.chars() {
-                let direction = match c {
-                    '^' => Direction::Up,
-                    '>' => Direction::Right,
-                    '<' => Direction::Left,
-                    'v' => Direction::Down,
+    let grid_lines = lines.by_ref().take_while(|l| !l.text.trim().is_empty());
+    let grid = Grid::from_lines_with(grid_lines, |c, idx| match c {
+        '#' => Ok(Tile::Wall),
+        '.' => Ok(Tile::Empty),
+        'O' => Ok(Tile::Box),
+        '@' => {
+            robot = Some(idx);
+            Ok(Tile::Empty)
+        }
+        _ => Err("one of `#`, `.`, `O`, `@`"),
+    })?;
+    let robot = robot.ok_or_else(|| ParseError::at_end(Day15::DAY, input, "a robot `@`"))?;
 
-                    _ => panic!("unknown direction {c}"),
-                };
-                movements.push(direction);
-            }
+    for line in lines {
+        for (x, c) in line.text.char_indices() {
+            let Some(direction) = Direction::from_char(c) else {
+                let found = &line.text[x..x + c.len_utf8()];
+                return Err(line.error_at(found, "one of `^`, `>`, `<`, `v`"));
+            };
+            movements.push(direction);
         }
     }
 
-    Input {
+    Ok(Input {
         grid,
         robot,
         movements,
-    }
+    })
 }
 
-fn print_state(input: &Input) {
-    for y in 0..input.grid.dimension().y {
-        for x in 0..input.grid.dimension().x {
-            let idx = uidx2(x, y);
-            match input.grid[idx] {
-                Tile::Empty => {
-                    if idx == input.robot {
-                        print!("@");
-                    } else {
-                        print!(".")
-                    }
-                }
-                Tile::Box => print!("O"),
-                Tile::LeftBox => print!("["),
-                Tile::RightBox => print!("]"),
-                Tile::Wall => print!("#"),
-            }
-        }
-        println!();
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid
+            .render(|_, tile| tile.to_char())
+            .overlay([self.robot], '@')
+            .fmt(f)
     }
 }
 
@@ -112,13 +90,11 @@ fn part1(input: &mut Input) -> u64 {
         match input.grid[target] {
             Tile::Empty => input.robot = target,
             Tile::Box => {
-                let mut end = target;
-                loop {
-                    end = direction.get_neighbor(end, &input.grid).unwrap();
-                    if input.grid[end] != Tile::Box {
-                        break;
-                    }
-                }
+                let (end, _) = input
+                    .grid
+                    .ray_until(target, *direction, |tile| *tile != Tile::Box)
+                    .last()
+                    .unwrap();
                 if input.grid[end] == Tile::Empty {
                     // move and push all boxes
                     input.grid[target] = Tile::Empty;
@@ -132,8 +108,6 @@ fn part1(input: &mut Input) -> u64 {
             _ => panic!("only single-tile boxes are allowed in part 1"),
         }
     }
-    print_state(input);
-
     score(input)
 }
 
@@ -233,8 +207,6 @@ fn part2(input: &mut Input) -> u64 {
         }
     }
 
-    println!("part 2 final state: ");
-    print_state(input);
     score(input)
 }
 
@@ -268,16 +240,29 @@ fn part2_input(input: &Input) -> Input {
     }
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day15.txt").unwrap();
+pub struct Day15;
 
-    let mut input = parse(&input);
-    let mut part2_input = part2_input(&input);
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(&mut input.clone()).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(&mut part2_input(input)).into()
+    }
+}
 
-    let part1_res = part1(&mut input);
-    println!("part 1 result: {part1_res}");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    print_state(&part2_input);
-    let part2_res = part2(&mut part2_input);
-    println!("part 2 result: {part2_res}");
+    crate::example_tests!(Day15: large, small);
 }
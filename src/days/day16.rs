@@ -0,0 +1,125 @@
+use crate::{
+    direction::Direction,
+    grid::Grid,
+    index2::UIndex2,
+    parse::ParseError,
+    search,
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Tile {
+    #[default]
+    Empty,
+    Wall,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    start: UIndex2,
+    end: UIndex2,
+    grid: Grid<Tile>,
+}
+
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut start = None;
+    let mut end = None;
+
+    let grid = Grid::from_str_with(Day16::DAY, input, |c, idx| match c {
+        '#' => Ok(Tile::Wall),
+        '.' => Ok(Tile::Empty),
+        'S' => {
+            start = Some(idx);
+            Ok(Tile::Empty)
+        }
+        'E' => {
+            end = Some(idx);
+            Ok(Tile::Empty)
+        }
+        _ => Err("one of `#`, `.`, `S`, `E`"),
+    })?;
+    let start = start.ok_or_else(|| ParseError::at_end(Day16::DAY, input, "a start tile `S`"))?;
+    let end = end.ok_or_else(|| ParseError::at_end(Day16::DAY, input, "an end tile `E`"))?;
+
+    Ok(Input { grid, start, end })
+}
+
+const COST_ROTATE: u64 = 1000;
+const COST_MOVE: u64 = 1;
+
+/// Turning in place, or moving forward if the tile ahead is empty.
+fn successors(
+    grid: &Grid<Tile>,
+    (position, facing): (UIndex2, Direction),
+) -> Vec<((UIndex2, Direction), u64)> {
+    let mut ret = vec![
+        ((position, facing.turn_clockwise()), COST_ROTATE),
+        ((position, facing.turn_counterclockwise()), COST_ROTATE),
+    ];
+    if let Some(next) = facing.get_neighbor(position, grid) {
+        if grid[next] == Tile::Empty {
+            ret.push(((next, facing), COST_MOVE));
+        }
+    }
+    ret
+}
+
+/// The lowest score of any path from start to end, or `None` if the end can't be reached.
+fn lowest_score(input: &Input) -> Option<u64> {
+    search::dijkstra(
+        (input.start, Direction::Left),
+        |&state| successors(&input.grid, state),
+        |(position, _)| *position == input.end,
+    )
+    .cost()
+}
+
+fn tiles_on_best_paths(input: &Input) -> Option<u64> {
+    let paths = search::dijkstra_all(
+        (input.start, Direction::Left),
+        |&state| successors(&input.grid, state),
+        |(position, _)| *position == input.end,
+    );
+    if paths.goals.is_empty() {
+        return None;
+    }
+    Some(paths.cells_on_paths(|(position, _)| *position).len() as _)
+}
+
+fn no_path() -> Answer {
+    Answer::Failed("no path from `S` to `E`".to_owned())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        lowest_score(input).map_or_else(no_path, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        tiles_on_best_paths(input).map_or_else(no_path, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day16: example);
+
+    #[test]
+    fn unreachable_end() {
+        let input = parse("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(lowest_score(&input), None);
+        assert!(matches!(Day16::part2(&input), Answer::Failed(_)));
+    }
+}
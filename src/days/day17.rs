@@ -0,0 +1,975 @@
+use std::{
+    fmt::{self, Debug, Display},
+    ops::BitXor,
+    str::FromStr,
+};
+
+use crate::{
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum OpCode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl TryFrom<u32> for OpCode {
+    type Error = ();
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Adv),
+            1 => Ok(Self::Bxl),
+            2 => Ok(Self::Bst),
+            3 => Ok(Self::Jnz),
+            4 => Ok(Self::Bxc),
+            5 => Ok(Self::Out),
+            6 => Ok(Self::Bdv),
+            7 => Ok(Self::Cdv),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The integer type of the VM's registers.
+pub trait Register:
+    Copy + Eq + Debug + Display + FromStr + BitXor<Output = Self> + From<u32>
+{
+    const ZERO: Self;
+
+    /// `self / 2^amount`, which is 0 once `amount` reaches the width of the type.
+    fn shift_right(self, amount: Self) -> Self;
+
+    /// `self % 8`.
+    fn low_bits(self) -> u32;
+}
+
+impl Register for u64 {
+    const ZERO: Self = 0;
+
+    fn shift_right(self, amount: Self) -> Self {
+        u32::try_from(amount)
+            .ok()
+            .and_then(|amount| self.checked_shr(amount))
+            .unwrap_or(0)
+    }
+
+    fn low_bits(self) -> u32 {
+        (self % 8) as u32
+    }
+}
+
+impl Register for u128 {
+    const ZERO: Self = 0;
+
+    fn shift_right(self, amount: Self) -> Self {
+        u32::try_from(amount)
+            .ok()
+            .and_then(|amount| self.checked_shr(amount))
+            .unwrap_or(0)
+    }
+
+    fn low_bits(self) -> u32 {
+        (self % 8) as u32
+    }
+}
+
+/// Default for how many instructions a program may execute before it's considered stuck.
+pub const STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone)]
+pub struct State<R = u64> {
+    instruction_pointer: usize,
+    // A, B, C
+    registers: [R; 3],
+    data: Vec<u32>,
+    output: Vec<u32>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Status {
+    Running,
+    Halted,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VmError {
+    /// Combo operand 7 is reserved.
+    InvalidComboOperand {
+        instruction_pointer: usize,
+    },
+    InvalidOpCode {
+        instruction_pointer: usize,
+        value: u32,
+    },
+    JumpOutOfRange {
+        instruction_pointer: usize,
+        target: u32,
+    },
+    StepLimitExceeded {
+        limit: usize,
+    },
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::InvalidComboOperand {
+                instruction_pointer,
+            } => write!(f, "invalid combo operand 7 at {instruction_pointer}"),
+            VmError::InvalidOpCode {
+                instruction_pointer,
+                value,
+            } => write!(f, "invalid opcode {value} at {instruction_pointer}"),
+            VmError::JumpOutOfRange {
+                instruction_pointer,
+                target,
+            } => write!(
+                f,
+                "jump from {instruction_pointer} to {target}, outside of the program"
+            ),
+            VmError::StepLimitExceeded { limit } => {
+                write!(f, "program did not halt within {limit} steps")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+fn literal_value(operand: u32) -> u32 {
+    operand
+}
+
+impl<R: Register> State<R> {
+    fn increase_instruction_pointer(&mut self) -> Status {
+        self.instruction_pointer += 2;
+        if self.instruction_pointer >= self.data.len() {
+            Status::Halted
+        } else {
+            Status::Running
+        }
+    }
+
+    fn combo_value(&self, operand: u32) -> Result<R, VmError> {
+        match operand {
+            0..=3 => Ok(operand.into()),
+            4..=6 => Ok(self.registers[operand as usize - 4]),
+            _ => Err(VmError::InvalidComboOperand {
+                instruction_pointer: self.instruction_pointer,
+            }),
+        }
+    }
+
+    /// Runs until the program halts, failing if that takes more than `step_limit` instructions.
+    fn evaluate(&mut self, step_limit: usize) -> Result<(), VmError> {
+        for _ in 0..step_limit {
+            if self.step()? == Status::Halted {
+                return Ok(());
+            }
+        }
+        Err(VmError::StepLimitExceeded { limit: step_limit })
+    }
+
+    fn step(&mut self) -> Result<Status, VmError> {
+        if self.instruction_pointer + 1 >= self.data.len() {
+            return Ok(Status::Halted);
+        }
+        let value = self.data[self.instruction_pointer];
+        let op_code = OpCode::try_from(value).map_err(|_| VmError::InvalidOpCode {
+            instruction_pointer: self.instruction_pointer,
+            value,
+        })?;
+        let operand = self.data[self.instruction_pointer + 1];
+        match op_code {
+            OpCode::Adv => {
+                self.registers[0] = self.registers[0].shift_right(self.combo_value(operand)?);
+            }
+            OpCode::Bxl => {
+                self.registers[1] = self.registers[1] ^ literal_value(operand).into();
+            }
+            OpCode::Bst => self.registers[1] = self.combo_value(operand)?.low_bits().into(),
+            OpCode::Jnz => {
+                if self.registers[0] == R::ZERO {
+                    // do nothing
+                } else {
+                    let target = literal_value(operand);
+                    if target as usize >= self.data.len() {
+                        return Err(VmError::JumpOutOfRange {
+                            instruction_pointer: self.instruction_pointer,
+                            target,
+                        });
+                    }
+                    self.instruction_pointer = target as usize;
+                    // do not increase instruction pointer
+                    return Ok(Status::Running);
+                }
+            }
+            OpCode::Bxc => {
+                self.registers[1] = self.registers[1] ^ self.registers[2];
+            }
+            OpCode::Out => {
+                self.output.push(self.combo_value(operand)?.low_bits());
+            }
+            OpCode::Bdv => {
+                // same as Adv, including reading, but result is stored in B
+                self.registers[1] = self.registers[0].shift_right(self.combo_value(operand)?);
+            }
+            OpCode::Cdv => {
+                // same as Adv, including reading, but result is stored in C
+                self.registers[2] = self.registers[0].shift_right(self.combo_value(operand)?);
+            }
+        }
+
+        Ok(self.increase_instruction_pointer())
+    }
+}
+
+/// A combo operand as it appears in listings.
+fn combo_name(operand: u32) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_owned(),
+        5 => "B".to_owned(),
+        6 => "C".to_owned(),
+        _ => "<invalid combo 7>".to_owned(),
+    }
+}
+
+/// A single decoded instruction, displayed as pseudo code, e.g. `A = A >> 3` or `out B % 8`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Instruction {
+    pub address: usize,
+    op_code: OpCode,
+    operand: u32,
+}
+
+impl Instruction {
+    /// Decodes the instruction at `address`, if that's a valid opcode followed by an operand.
+    pub fn decode(data: &[u32], address: usize) -> Option<Self> {
+        let op_code = OpCode::try_from(*data.get(address)?).ok()?;
+        let operand = *data.get(address + 1)?;
+        Some(Self {
+            address,
+            op_code,
+            operand,
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let literal = literal_value(self.operand);
+        let combo = combo_name(self.operand);
+        match self.op_code {
+            OpCode::Adv => write!(f, "A = A >> {combo}"),
+            OpCode::Bxl => write!(f, "B = B ^ {literal}"),
+            OpCode::Bst => write!(f, "B = {combo} % 8"),
+            OpCode::Jnz => write!(f, "jnz {literal}"),
+            OpCode::Bxc => write!(f, "B = B ^ C"),
+            OpCode::Out => write!(f, "out {combo} % 8"),
+            OpCode::Bdv => write!(f, "B = A >> {combo}"),
+            OpCode::Cdv => write!(f, "C = A >> {combo}"),
+        }
+    }
+}
+
+/// Decodes the program into instructions, or returns the address of the first invalid opcode.
+pub fn disassemble(data: &[u32]) -> Result<Vec<Instruction>, usize> {
+    (0..data.len())
+        .step_by(2)
+        .map(|address| Instruction::decode(data, address).ok_or(address))
+        .collect()
+}
+
+/// A register value within one iteration of a decompiled loop, in terms of the register values at the start
+/// of the iteration.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expr {
+    A,
+    B,
+    C,
+    Literal(u32),
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Box<Expr>, Box<Expr>),
+    Mod8(Box<Expr>),
+}
+
+impl Expr {
+    fn shr(lhs: Expr, rhs: Expr) -> Expr {
+        match rhs {
+            Expr::Literal(0) => lhs,
+            rhs => Expr::Shr(Box::new(lhs), Box::new(rhs)),
+        }
+    }
+
+    fn xor(lhs: Expr, rhs: Expr) -> Expr {
+        match (lhs, rhs) {
+            (Expr::Literal(l), Expr::Literal(r)) => Expr::Literal(l ^ r),
+            (lhs, Expr::Literal(0)) => lhs,
+            (lhs, rhs) => Expr::Xor(Box::new(lhs), Box::new(rhs)),
+        }
+    }
+
+    fn mod8(self) -> Expr {
+        match self {
+            Expr::Literal(l) => Expr::Literal(l % 8),
+            Expr::Mod8(_) => self,
+            e => Expr::Mod8(Box::new(e)),
+        }
+    }
+
+    fn uses(&self, register: &Expr) -> bool {
+        match self {
+            Expr::Shr(l, r) | Expr::Xor(l, r) => l.uses(register) || r.uses(register),
+            Expr::Mod8(e) => e.uses(register),
+            e => e == register,
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Shr(..) | Expr::Xor(..) | Expr::Mod8(_) => write!(f, "({self})"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::A => write!(f, "a"),
+            Expr::B => write!(f, "b"),
+            Expr::C => write!(f, "c"),
+            Expr::Literal(l) => write!(f, "{l}"),
+            Expr::Shr(l, r) => {
+                l.fmt_nested(f)?;
+                write!(f, " >> ")?;
+                r.fmt_nested(f)
+            }
+            Expr::Xor(l, r) => {
+                // xor is associative, so chains don't need parentheses
+                for (i, e) in [l, r].into_iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ^ ")?;
+                    }
+                    if let Expr::Xor(..) = **e {
+                        write!(f, "{e}")?;
+                    } else {
+                        e.fmt_nested(f)?;
+                    }
+                }
+                Ok(())
+            }
+            Expr::Mod8(e) => {
+                e.fmt_nested(f)?;
+                write!(f, " % 8")
+            }
+        }
+    }
+}
+
+/// A program of the common single-loop shape: straight-line code ending in `jnz 0`, with one `out`.
+///
+/// Displays as equivalent Rust code, where `b` and `c` are only updated if a later iteration reads them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Decompiled {
+    pub output: Expr,
+    /// The registers at the end of an iteration.
+    pub next: [Expr; 3],
+}
+
+impl Decompiled {
+    pub fn new(data: &[u32]) -> Option<Self> {
+        let instructions = disassemble(data).ok()?;
+        let (last, body) = instructions.split_last()?;
+        if last.op_code != OpCode::Jnz || last.operand != 0 {
+            return None;
+        }
+
+        let mut registers = [Expr::A, Expr::B, Expr::C];
+        let mut output = None;
+        for instruction in body {
+            let combo = match instruction.operand {
+                0..=3 => Expr::Literal(instruction.operand),
+                4..=6 => registers[instruction.operand as usize - 4].clone(),
+                _ => return None,
+            };
+            let literal = Expr::Literal(literal_value(instruction.operand));
+            let [a, b, c] = &registers;
+            match instruction.op_code {
+                OpCode::Adv => registers[0] = Expr::shr(a.clone(), combo),
+                OpCode::Bxl => registers[1] = Expr::xor(b.clone(), literal),
+                OpCode::Bst => registers[1] = combo.mod8(),
+                OpCode::Jnz => return None,
+                OpCode::Bxc => registers[1] = Expr::xor(b.clone(), c.clone()),
+                OpCode::Out => {
+                    if output.replace(combo.mod8()).is_some() {
+                        return None;
+                    }
+                }
+                OpCode::Bdv => registers[1] = Expr::shr(a.clone(), combo),
+                OpCode::Cdv => registers[2] = Expr::shr(a.clone(), combo),
+            }
+        }
+
+        Some(Self {
+            output: output?,
+            next: registers,
+        })
+    }
+}
+
+impl Display for Decompiled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c] = &self.next;
+        let carried = [(Expr::B, b), (Expr::C, c)]
+            .into_iter()
+            .filter(|(register, _)| {
+                self.output.uses(register) || self.next.iter().any(|e| e.uses(register))
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(f, "loop {{")?;
+        writeln!(f, "    out.push({});", self.output)?;
+        if carried.is_empty() {
+            writeln!(f, "    a = {a};")?;
+        } else {
+            let names = carried.iter().map(|(r, _)| r.to_string());
+            let values = carried.iter().map(|(_, e)| e.to_string());
+            let names = ["a".to_owned()].into_iter().chain(names);
+            let values = [a.to_string()].into_iter().chain(values);
+            writeln!(
+                f,
+                "    ({}) = ({});",
+                names.collect::<Vec<_>>().join(", "),
+                values.collect::<Vec<_>>().join(", ")
+            )?;
+        }
+        writeln!(f, "    if a == 0 {{")?;
+        writeln!(f, "        break;")?;
+        writeln!(f, "    }}")?;
+        write!(f, "}}")
+    }
+}
+
+impl<R> State<R> {
+    /// The program as a listing, one `address: instruction` per line.
+    pub fn disassemble(&self) -> String {
+        match disassemble(&self.data) {
+            Ok(instructions) => instructions
+                .iter()
+                .map(|i| format!("{:3}: {i}", i.address))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(address) => format!("invalid instruction at {address}"),
+        }
+    }
+
+    /// The program as a single loop in Rust, if it has the usual shape.
+    pub fn decompile(&self) -> Option<Decompiled> {
+        Decompiled::new(&self.data)
+    }
+}
+
+/// Where [`State::trace`] should stop early.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// Before executing the instruction at this address.
+    InstructionPointer(usize),
+    /// After the instruction that makes the output this many values long.
+    OutputLength(usize),
+}
+
+/// Why [`State::trace`] stopped.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(Breakpoint),
+    Error(VmError),
+}
+
+/// One executed instruction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TraceEntry<R> {
+    pub step: usize,
+    pub instruction_pointer: usize,
+    /// The instruction as shown by the disassembler.
+    pub instruction: String,
+    pub before: [R; 3],
+    pub after: [R; 3],
+    /// The value written by an `out` instruction.
+    pub output: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trace<R> {
+    pub entries: Vec<TraceEntry<R>>,
+    pub stop: Stop,
+}
+
+impl<R: Register> Trace<R> {
+    pub fn to_csv(&self) -> String {
+        let mut ret =
+            "step,ip,instruction,a_before,b_before,c_before,a_after,b_after,c_after,output\n"
+                .to_owned();
+        for e in &self.entries {
+            let [a, b, c] = e.before;
+            let [a_after, b_after, c_after] = e.after;
+            let output = e.output.map(|o| o.to_string()).unwrap_or_default();
+            ret += &format!(
+                "{},{},{},{a},{b},{c},{a_after},{b_after},{c_after},{output}\n",
+                e.step, e.instruction_pointer, e.instruction
+            );
+        }
+        ret
+    }
+
+    /// A JSON array with one object per entry; register values are written as plain numbers.
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|e| {
+                let [a, b, c] = e.before;
+                let [a_after, b_after, c_after] = e.after;
+                let output = e.output.map(|o| o.to_string());
+                format!(
+                    r#"{{"step":{},"ip":{},"instruction":"{}","before":[{a},{b},{c}],"after":[{a_after},{b_after},{c_after}],"output":{}}}"#,
+                    e.step,
+                    e.instruction_pointer,
+                    e.instruction,
+                    output.as_deref().unwrap_or("null")
+                )
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", entries.join(",\n "))
+    }
+}
+
+/// Options for [`State::trace`].
+#[derive(Debug, Clone)]
+pub struct Tracer {
+    breakpoints: Vec<Breakpoint>,
+    step_limit: usize,
+}
+
+impl Default for Tracer {
+    fn default() -> Self {
+        Self {
+            breakpoints: vec![],
+            step_limit: STEP_LIMIT,
+        }
+    }
+}
+
+impl Tracer {
+    pub fn with_breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    /// Checks the breakpoints before a step, or after it when given the output length the step
+    /// started with.
+    fn breakpoint_hit<R>(
+        &self,
+        state: &State<R>,
+        stepped_from: Option<usize>,
+    ) -> Option<Breakpoint> {
+        self.breakpoints
+            .iter()
+            .copied()
+            .find(|b| match (b, stepped_from) {
+                (Breakpoint::InstructionPointer(ip), None) => state.instruction_pointer == *ip,
+                (Breakpoint::OutputLength(len), Some(output_len)) => {
+                    output_len < *len && state.output.len() >= *len
+                }
+                _ => false,
+            })
+    }
+}
+
+impl<R: Register> State<R> {
+    /// Runs like `evaluate`, recording every executed instruction.
+    ///
+    /// Tracing can be resumed after a breakpoint by calling this again; an instruction pointer breakpoint
+    /// at the current instruction is not hit again on the first step, and an output length breakpoint
+    /// that was already reached is not hit again.
+    pub fn trace(&mut self, tracer: &Tracer) -> Trace<R> {
+        let mut entries = vec![];
+
+        let stop = loop {
+            if entries.len() == tracer.step_limit {
+                break Stop::Error(VmError::StepLimitExceeded {
+                    limit: tracer.step_limit,
+                });
+            }
+            if !entries.is_empty() {
+                if let Some(breakpoint) = tracer.breakpoint_hit(self, None) {
+                    break Stop::Breakpoint(breakpoint);
+                }
+            }
+
+            let instruction_pointer = self.instruction_pointer;
+            let instruction = Instruction::decode(&self.data, instruction_pointer)
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+            let before = self.registers;
+            let output_len = self.output.len();
+
+            let status = match self.step() {
+                Ok(status) => status,
+                Err(e) => break Stop::Error(e),
+            };
+            if status == Status::Halted && instruction_pointer == self.instruction_pointer {
+                // nothing was executed
+                break Stop::Halted;
+            }
+
+            entries.push(TraceEntry {
+                step: entries.len(),
+                instruction_pointer,
+                instruction,
+                before,
+                after: self.registers,
+                output: self.output.get(output_len).copied(),
+            });
+
+            if let Some(breakpoint) = tracer.breakpoint_hit(self, Some(output_len)) {
+                break Stop::Breakpoint(breakpoint);
+            }
+            if status == Status::Halted {
+                break Stop::Halted;
+            }
+        };
+
+        Trace { entries, stop }
+    }
+}
+
+fn parse<R: Register>(input: &str) -> Result<State<R>, ParseError> {
+    let mut it = parse::lines(Day17::DAY, input);
+    let mut next_line = |expected: &str| {
+        it.next()
+            .ok_or_else(|| ParseError::at_end(Day17::DAY, input, expected))
+    };
+
+    fn extract_value<R: FromStr>(line: Line) -> Result<R, ParseError> {
+        let Some((_, value)) = line.text.split_once(":") else {
+            return Err(line.error("`Register <name>: <value>`"));
+        };
+        line.parse(value.trim(), "a register value")
+    }
+    let a = extract_value(next_line("`Register A: <value>`")?)?;
+    let b = extract_value(next_line("`Register B: <value>`")?)?;
+    let c = extract_value(next_line("`Register C: <value>`")?)?;
+    let separator = next_line("an empty line")?;
+    if !separator.text.trim().is_empty() {
+        return Err(separator.error("an empty line"));
+    }
+
+    let program = next_line("`Program: <data>`")?;
+    let Some((_, data)) = program.text.split_once(":") else {
+        return Err(program.error("`Program: <data>`"));
+    };
+    let data = data
+        .trim()
+        .split(",")
+        .map(|d| program.parse(d, "a number"))
+        .collect::<Result<_, _>>()?;
+
+    Ok(State {
+        instruction_pointer: 0,
+        registers: [a, b, c],
+        data,
+        output: vec![],
+    })
+}
+
+fn part1<R: Register>(state: &mut State<R>) -> Result<(), VmError> {
+    state.evaluate(STEP_LIMIT)
+}
+
+impl<R: Register> State<R> {
+    /// Runs the program from the start with register A set to `a`, returning the output.
+    fn run_with_a(&self, a: R) -> Result<Vec<u32>, VmError> {
+        let mut state = self.clone();
+        state.instruction_pointer = 0;
+        state.registers[0] = a;
+        state.output.clear();
+        state.evaluate(STEP_LIMIT)?;
+        Ok(state.output)
+    }
+}
+
+/// Finds the lowest initial value of A for which the program outputs itself.
+///
+/// Assumes the usual shape of the program: a single loop that outputs one value depending on the lowest
+/// bits of A, then shifts A right by 3 bits until it reaches 0. The last output then only depends on the
+/// highest 3 bits of A, so A can be reconstructed 3 bits at a time, starting from the end of the program.
+fn part2(state: &State) -> Option<u64> {
+    let outputs = |a: u64| state.run_with_a(a).ok();
+
+    let mut candidates = vec![0];
+    for i in (0..state.data.len()).rev() {
+        candidates = candidates
+            .into_iter()
+            // stop once the candidates no longer fit, rather than overflowing
+            .filter(|a: &u64| a.leading_zeros() >= 3)
+            .flat_map(|a| (0..8).map(move |bits| a << 3 | bits))
+            .filter(|a| outputs(*a).is_some_and(|output| output == state.data[i..]))
+            .collect();
+    }
+
+    // verify by re-running the full program, since the assumption above might not hold
+    candidates
+        .into_iter()
+        .filter(|a| *a > 0 && outputs(*a).is_some_and(|output| output == state.data))
+        .min()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = State;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(state: &Self::Input) -> Answer {
+        let mut state = state.clone();
+        if let Err(e) = part1(&mut state) {
+            return Answer::Failed(e.to_string());
+        }
+
+        state
+            .output
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+            .into()
+    }
+
+    fn part2(state: &Self::Input) -> Answer {
+        match part2(state) {
+            Some(a) => a.into(),
+            None => {
+                Answer::Failed("no value of register A makes the program output itself".to_owned())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day17: example, quine);
+
+    fn program<R: Register>(a: &str, data: &str) -> State<R> {
+        parse(&format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {data}\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_separator() {
+        let err = parse::<u64>("Register A: 1\nRegister B: 0\nRegister C: 0\nProgram: 5,4\n")
+            .unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (4, "an empty line"));
+    }
+
+    #[test]
+    fn test_quine() {
+        let state = program("2024", "0,3,5,4,3,0");
+        assert_eq!(part2(&state), Some(117440));
+        assert_eq!(state.run_with_a(117440), Ok(state.data.clone()));
+
+        // outputs a single value, so it can never reproduce both of its words
+        let state = program("1", "5,4");
+        assert_eq!(part2(&state), None);
+        assert!(matches!(Day17::part2(&state), Answer::Failed(_)));
+    }
+
+    #[test]
+    fn test_wide_registers() {
+        // B = A >> 3, with A = 2^100
+        let mut state: State<u128> = program("1267650600228229401496703205376", "6,3");
+        part1(&mut state).unwrap();
+        assert_eq!(state.registers[1], 1 << 97);
+
+        // shifting by at least the register width gives 0 rather than overflowing
+        let mut state: State<u64> = program("0", "7,4,5,6");
+        state.registers[0] = u64::MAX;
+        state.registers[2] = 5;
+        part1(&mut state).unwrap();
+        assert_eq!(state.registers[2], 0);
+        assert_eq!(state.output, vec![0]);
+    }
+
+    #[test]
+    fn test_vm_errors() {
+        let mut state: State = program("1", "5,7");
+        assert_eq!(
+            part1(&mut state),
+            Err(VmError::InvalidComboOperand {
+                instruction_pointer: 0
+            })
+        );
+
+        let mut state: State = program("1", "3,6");
+        assert_eq!(
+            part1(&mut state),
+            Err(VmError::JumpOutOfRange {
+                instruction_pointer: 0,
+                target: 6
+            })
+        );
+
+        let mut state: State = program("1", "3,0");
+        assert_eq!(
+            part1(&mut state),
+            Err(VmError::StepLimitExceeded { limit: STEP_LIMIT })
+        );
+
+        let state: State = program("1", "5,7");
+        assert!(matches!(Day17::part1(&state), Answer::Failed(_)));
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let state: State = program("0", "2,4,1,5,7,5,0,3,4,0,1,6,5,5,3,0");
+        assert_eq!(
+            state.disassemble(),
+            "  0: B = A % 8
+  2: B = B ^ 5
+  4: C = A >> B
+  6: A = A >> 3
+  8: B = B ^ C
+ 10: B = B ^ 6
+ 12: out B % 8
+ 14: jnz 0"
+        );
+
+        let decompiled = state.decompile().unwrap();
+        assert_eq!(
+            decompiled.output.to_string(),
+            "((a % 8) ^ 5 ^ (a >> ((a % 8) ^ 5)) ^ 6) % 8"
+        );
+        assert_eq!(
+            decompiled.to_string(),
+            "loop {
+    out.push(((a % 8) ^ 5 ^ (a >> ((a % 8) ^ 5)) ^ 6) % 8);
+    a = a >> 3;
+    if a == 0 {
+        break;
+    }
+}"
+        );
+
+        let state: State = program("0", "0,1,5,4,3,0");
+        assert_eq!(
+            state.decompile().unwrap().output.to_string(),
+            "(a >> 1) % 8"
+        );
+        let state: State = program("0", "3,0,5,4,3,0");
+        assert_eq!(state.decompile(), None);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut state: State = program("10", "0,1,5,4,3,0");
+        let trace = state.trace(&Tracer::default().with_breakpoint(Breakpoint::OutputLength(2)));
+        assert_eq!(trace.stop, Stop::Breakpoint(Breakpoint::OutputLength(2)));
+        assert_eq!(state.output, vec![5, 2]);
+        assert_eq!(trace.entries.len(), 5);
+        assert_eq!(
+            trace.entries[1],
+            TraceEntry {
+                step: 1,
+                instruction_pointer: 2,
+                instruction: "out A % 8".to_owned(),
+                before: [5, 0, 0],
+                after: [5, 0, 0],
+                output: Some(5),
+            }
+        );
+
+        // resuming doesn't stop at the current instruction again
+        let tracer = Tracer::default().with_breakpoint(Breakpoint::InstructionPointer(4));
+        let trace = state.trace(&tracer);
+        assert_eq!(
+            trace.stop,
+            Stop::Breakpoint(Breakpoint::InstructionPointer(4))
+        );
+        assert_eq!(state.output, vec![5, 2, 1]);
+        assert_eq!(
+            trace.to_csv().lines().nth(1),
+            Some("0,4,jnz 0,2,0,0,2,0,0,")
+        );
+
+        let trace = state.trace(&Tracer::default());
+        assert_eq!(trace.stop, Stop::Halted);
+        assert_eq!(state.output, vec![5, 2, 1, 0]);
+
+        // resuming after an output length breakpoint runs until the output grows past it
+        let mut state: State = program("10", "0,1,5,4,3,0");
+        let tracer = Tracer::default().with_breakpoint(Breakpoint::OutputLength(2));
+        state.trace(&tracer);
+        let trace = state.trace(&tracer);
+        assert_eq!(trace.stop, Stop::Halted);
+        assert_eq!(state.output, vec![5, 2, 1, 0]);
+        assert_eq!(trace.entries.len(), 7);
+
+        let mut state: State = program("10", "0,1,5,4,3,0");
+        let trace =
+            state.trace(&Tracer::default().with_breakpoint(Breakpoint::InstructionPointer(4)));
+        assert_eq!(
+            trace.stop,
+            Stop::Breakpoint(Breakpoint::InstructionPointer(4))
+        );
+        assert_eq!(
+            trace.to_json(),
+            r#"[{"step":0,"ip":0,"instruction":"A = A >> 1","before":[10,0,0],"after":[5,0,0],"output":null},
+ {"step":1,"ip":2,"instruction":"out A % 8","before":[5,0,0],"after":[5,0,0],"output":5}]"#
+        );
+
+        let mut state: State = program("1", "3,0");
+        let trace = state.trace(&Tracer::default().with_step_limit(10));
+        assert_eq!(
+            trace.stop,
+            Stop::Error(VmError::StepLimitExceeded { limit: 10 })
+        );
+        assert_eq!(trace.entries.len(), 10);
+
+        // a trailing invalid word doesn't affect decoding the instructions before it
+        let mut state: State = program("10", "0,1,5,4,3,0,9");
+        let trace = state.trace(&Tracer::default().with_breakpoint(Breakpoint::OutputLength(1)));
+        assert_eq!(
+            trace
+                .entries
+                .iter()
+                .map(|e| e.instruction.as_str())
+                .collect::<Vec<_>>(),
+            vec!["A = A >> 1", "out A % 8"]
+        );
+    }
+}
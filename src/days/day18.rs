@@ -0,0 +1,178 @@
+use crate::{
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+    parse::{self, ParseError},
+    search,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Input {
+    bytes: Vec<UIndex2>,
+    /// The memory space's size, which isn't part of the input.
+    size: UIndex2,
+    /// How many bytes have fallen when part 1 looks for a path.
+    fallen: usize,
+}
+
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let bytes = parse::lines(Day18::DAY, input)
+        .map(|line| {
+            let Some((a, b)) = line.text.trim().split_once(",") else {
+                return Err(line.error("`x,y`"));
+            };
+            let coordinate = |token, size| {
+                let expected = format!("a coordinate below {size}");
+                match line.parse(token, &expected)? {
+                    value if value < size => Ok(value),
+                    _ => Err(line.error_at(token, expected)),
+                }
+            };
+            Ok(uidx2(
+                coordinate(a, GRID_SIZE.x)?,
+                coordinate(b, GRID_SIZE.y)?,
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Input {
+        bytes,
+        size: GRID_SIZE,
+        fallen: PART1_LIMIT,
+    })
+}
+
+/// An empty memory space, unless a byte falls outside of it.
+fn memory_space(input: &Input) -> Result<Grid<Tile>, Answer> {
+    match input
+        .bytes
+        .iter()
+        .find(|byte| byte.x >= input.size.x || byte.y >= input.size.y)
+    {
+        Some(byte) => Err(Answer::Failed(format!(
+            "byte {},{} is outside the memory space",
+            byte.x, byte.y
+        ))),
+        None => Ok(Grid::new_with_default(input.size)),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum Tile {
+    #[default]
+    Empty,
+    Blocked,
+}
+
+fn shortest_path(indices: &[UIndex2], grid: &mut Grid<Tile>) -> Option<u64> {
+    for idx in indices.iter().cloned() {
+        grid[idx] = Tile::Blocked;
+    }
+
+    let grid = &*grid;
+    let start = uidx2(0, 0);
+    let target = grid.dimension() - uidx2(1, 1);
+
+    search::bfs(
+        start,
+        |&idx| {
+            grid.neighbor_indices4(idx)
+                .filter(|n| grid[*n] == Tile::Empty)
+        },
+        |&idx| idx == target,
+    )
+    .cost()
+}
+
+/// The index of the first byte that blocks every path to the exit, if any does.
+fn part2(indices: &[UIndex2], grid: &mut Grid<Tile>) -> Option<usize> {
+    let blocking = (1..=indices.len())
+        .collect::<Vec<_>>()
+        .partition_point(|i| {
+            grid.reset_to_default();
+            shortest_path(&indices[..*i], grid).is_some()
+        });
+    (blocking < indices.len()).then_some(blocking)
+}
+
+const GRID_SIZE: UIndex2 = UIndex2 { x: 71, y: 71 };
+const PART1_LIMIT: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    const PARAMS: &'static [&'static str] = &["size", "fallen"];
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn set_param(input: &mut Self::Input, name: &str, value: u64) -> Result<(), String> {
+        match name {
+            "size" => {
+                let size = u32::try_from(value)
+                    .ok()
+                    .filter(|size| *size > 0)
+                    .ok_or("the memory space must not be empty")?;
+                input.size = uidx2(size, size);
+            }
+            "fallen" => input.fallen = value as usize,
+            _ => return Err(format!("no parameter `{name}`")),
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let Some(fallen) = input.bytes.get(..input.fallen) else {
+            return Answer::Failed(format!(
+                "expected at least {} bytes, got {}",
+                input.fallen,
+                input.bytes.len()
+            ));
+        };
+        let mut grid = match memory_space(input) {
+            Ok(grid) => grid,
+            Err(failed) => return failed,
+        };
+        match shortest_path(fallen, &mut grid) {
+            Some(steps) => steps.into(),
+            None => Answer::Failed("no path to the exit".to_owned()),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut grid = match memory_space(input) {
+            Ok(grid) => grid,
+            Err(failed) => return failed,
+        };
+        match part2(&input.bytes, &mut grid) {
+            Some(i) => format!("{},{}", input.bytes[i].x, input.bytes[i].y).into(),
+            None => Answer::Failed("no byte blocks the exit".to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day18: example);
+
+    #[test]
+    fn invalid_input() {
+        let err = parse("1,2\n3,71\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "71"));
+
+        let input = parse("1,2\n3,4\n").unwrap();
+        assert!(matches!(Day18::part1(&input), Answer::Failed(_)));
+        assert!(matches!(Day18::part2(&input), Answer::Failed(_)));
+
+        let mut input = parse("1,2\n3,4\n").unwrap();
+        Day18::set_param(&mut input, "size", 4).unwrap();
+        assert!(matches!(Day18::part2(&input), Answer::Failed(_)));
+        assert!(Day18::set_param(&mut input, "size", 0).is_err());
+    }
+}
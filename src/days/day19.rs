@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::{
+    parallel,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Input {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut it = parse::lines(Day19::DAY, input);
+    let towels = it
+        .next()
+        .ok_or_else(|| ParseError::in_input(Day19::DAY, input, input, "a list of towels"))?
+        .text
+        .split(",")
+        .map(|s| s.trim().to_owned())
+        .collect();
+
+    if let Some(separator) = it.next() {
+        if !separator.text.trim().is_empty() {
+            return Err(separator.error("an empty line"));
+        }
+    }
+    let designs = it.map(|line| line.text.to_owned()).collect();
+
+    Ok(Input { towels, designs })
+}
+
+#[derive(Debug, Default)]
+pub struct TrieNode {
+    leaf: bool,
+    children: HashMap<char, Box<TrieNode>>,
+}
+
+impl TrieNode {
+    fn insert(&mut self, s: &str) {
+        let mut node = self;
+        for c in s.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.leaf = true;
+    }
+
+    fn child(&self, c: char) -> Option<&TrieNode> {
+        self.children.get(&c).map(|node| node.as_ref())
+    }
+}
+
+fn check_design(design: &str, root: &TrieNode) -> usize {
+    let mut paths = vec![0; design.len() + 1];
+    paths[0] = 1;
+
+    for i in 0..design.len() {
+        let mut node = root;
+        if paths[i] == 0 {
+            continue;
+        }
+
+        for (idx, c) in design.char_indices().skip(i) {
+            if let Some(n) = node.child(c) {
+                node = n;
+                if node.leaf {
+                    paths[idx + 1] += paths[i];
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    *paths.last().unwrap()
+}
+
+fn part1(designs: &[String], trie: &TrieNode) -> usize {
+    parallel::sum(designs, |d| (check_design(d, trie) > 0) as u64) as usize
+}
+
+fn part2(designs: &[String], trie: &TrieNode) -> usize {
+    parallel::sum(designs, |d| check_design(d, trie) as u64) as usize
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Input = (Input, TrieNode);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = parse(input)?;
+        let trie = build_trie(&input);
+        Ok((input, trie))
+    }
+
+    fn part1((input, trie): &Self::Input) -> Answer {
+        part1(&input.designs, trie).into()
+    }
+
+    fn part2((input, trie): &Self::Input) -> Answer {
+        part2(&input.designs, trie).into()
+    }
+}
+
+fn build_trie(input: &Input) -> TrieNode {
+    let mut ret = TrieNode::default();
+
+    for towel in &input.towels {
+        ret.insert(towel);
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day19: example);
+
+    #[test]
+    fn missing_separator() {
+        let err = parse("r, wr, b\nbrwrr\nbggr\n").unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (2, "brwrr"));
+        assert_eq!(err.expected, "an empty line");
+    }
+}
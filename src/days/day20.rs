@@ -0,0 +1,168 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use crate::{
+    grid::Grid,
+    index2::{Index2, UIndex2},
+    parse::ParseError,
+    search,
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum Tile {
+    #[default]
+    Empty,
+    Wall,
+}
+
+/// How many picoseconds a cheat must save to be counted, unless overridden.
+const MIN_SAVING: u32 = 100;
+
+#[derive(Debug)]
+pub struct Input {
+    start: UIndex2,
+    end: UIndex2,
+    grid: Grid<Tile>,
+    min_saving: u32,
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid
+            .render(|_, tile| match tile {
+                Tile::Empty => '.',
+                Tile::Wall => '#',
+            })
+            .overlay([self.start], 'S')
+            .overlay([self.end], 'E')
+            .fmt(f)
+    }
+}
+
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut start = None;
+    let mut end = None;
+
+    let grid = Grid::from_str_with(Day20::DAY, input, |c, idx| match c {
+        '#' => Ok(Tile::Wall),
+        '.' => Ok(Tile::Empty),
+        'S' => {
+            start = Some(idx);
+            Ok(Tile::Empty)
+        }
+        'E' => {
+            end = Some(idx);
+            Ok(Tile::Empty)
+        }
+        _ => Err("one of `#`, `.`, `S`, `E`"),
+    })?;
+    let start = start.ok_or_else(|| ParseError::at_end(Day20::DAY, input, "a start tile `S`"))?;
+    let end = end.ok_or_else(|| ParseError::at_end(Day20::DAY, input, "an end tile `E`"))?;
+
+    Ok(Input {
+        grid,
+        start,
+        end,
+        min_saving: MIN_SAVING,
+    })
+}
+
+fn shortcut_savings(input: &Input, cheat_len: u32) -> HashMap<u32, u32> {
+    let distance_from_start = search::bfs(
+        input.start,
+        |&node| {
+            input
+                .grid
+                .neighbor_indices4(node)
+                .filter(|n| input.grid[*n] == Tile::Empty)
+        },
+        |_| false,
+    )
+    .distances;
+
+    let mut shortcut_savings = HashMap::new();
+
+    for node in distance_from_start.keys().cloned() {
+        let cheat_len = cheat_len as i32;
+        for x in -cheat_len..=cheat_len {
+            for y in -cheat_len..=cheat_len {
+                let manhattan_distance = x.abs() + y.abs();
+                if manhattan_distance > cheat_len {
+                    continue;
+                };
+
+                let offset = Index2::new(x, y);
+                let target = node.to_index2() + offset;
+                let Some(target) = target.to_index2() else {
+                    continue;
+                };
+
+                let Some(target_tile) = input.grid.get(target) else {
+                    continue;
+                };
+                if *target_tile == Tile::Wall {
+                    continue;
+                }
+
+                let savings = distance_from_start[&target] as i32
+                    - distance_from_start[&node] as i32
+                    - manhattan_distance;
+                if savings > 0 {
+                    let count = shortcut_savings.entry(savings as u32).or_default();
+                    *count += 1;
+                }
+            }
+        }
+    }
+    shortcut_savings
+}
+
+fn find_savings(input: &Input, cheat_len: u32) -> u32 {
+    let shortcut_savings = shortcut_savings(input, cheat_len);
+    shortcut_savings
+        .into_iter()
+        .filter_map(|(k, v)| (k >= input.min_saving).then_some(v))
+        .sum()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    const PARAMS: &'static [&'static str] = &["min_saving"];
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn set_param(input: &mut Self::Input, name: &str, value: u64) -> Result<(), String> {
+        match name {
+            "min_saving" => {
+                input.min_saving = u32::try_from(value).map_err(|_| "too many picoseconds")?
+            }
+            _ => return Err(format!("no parameter `{name}`")),
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        find_savings(input, 2).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_savings(input, 20).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day20: example);
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use crate::{
+    direction::Direction,
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Keypad {
+    pub grid: Grid<Option<char>>,
+}
+
+impl Keypad {
+    pub fn directional() -> Self {
+        let mut grid = Grid::new_with_default(uidx2(3, 2));
+        // None, ^, A
+        // (0, 0) stays None
+        grid[uidx2(1, 0)] = Some('^');
+        grid[uidx2(2, 0)] = Some('A');
+
+        // <, v, >
+        grid[uidx2(0, 1)] = Some('<');
+        grid[uidx2(1, 1)] = Some('v');
+        grid[uidx2(2, 1)] = Some('>');
+
+        Self { grid }
+    }
+
+    pub fn numeric() -> Self {
+        let mut grid = Grid::new_with_default(uidx2(3, 4));
+        // 7, 8, 9
+        // 4, 5, 6
+        // 1, 2, 3
+        for (i, c) in "789456123".chars().enumerate() {
+            grid[uidx2(i as u32 % 3, i as u32 / 3)] = Some(c);
+        }
+
+        // None, 0, A
+        // (0, 3) stays None
+        grid[uidx2(1, 3)] = Some('0');
+        grid[uidx2(2, 3)] = Some('A');
+
+        Self { grid }
+    }
+
+    fn position(&self, key: char) -> UIndex2 {
+        (0..self.grid.dimension().y)
+            .flat_map(|y| (0..self.grid.dimension().x).map(move |x| uidx2(x, y)))
+            .find(|idx| self.grid[*idx] == Some(key))
+            .unwrap_or_else(|| panic!("no key {key} on this keypad"))
+    }
+
+    /// The shortest ways to move from `from` to `to` and press it, as directional key presses.
+    ///
+    /// Only sequences that do all moves along one axis first are considered, since alternating between
+    /// axes costs additional moves on the keypads further up the chain. Sequences over the gap are skipped.
+    fn sequences(&self, from: char, to: char) -> Vec<String> {
+        let from = self.position(from);
+        let to = self.position(to);
+
+        let horizontal = if to.x > from.x {
+            (Direction::Right, to.x - from.x)
+        } else {
+            (Direction::Left, from.x - to.x)
+        };
+        let vertical = if to.y > from.y {
+            (Direction::Down, to.y - from.y)
+        } else {
+            (Direction::Up, from.y - to.y)
+        };
+
+        let mut ret = vec![];
+        for moves in [[horizontal, vertical], [vertical, horizontal]] {
+            let mut position = from;
+            let mut sequence = String::new();
+            let mut valid = true;
+            for (direction, count) in moves {
+                for _ in 0..count {
+                    position = direction.get_neighbor(position, &self.grid).unwrap();
+                    valid &= self.grid[position].is_some();
+                    sequence.push(direction.to_char());
+                }
+            }
+            sequence.push('A');
+            if valid && !ret.contains(&sequence) {
+                ret.push(sequence);
+            }
+        }
+        ret
+    }
+}
+
+/// A numeric keypad, operated through a chain of robots at directional keypads.
+struct Chain {
+    numeric: Keypad,
+    directional: Keypad,
+    // (from, to, robots) -> number of presses
+    memo: HashMap<(char, char, usize), u64>,
+}
+
+impl Chain {
+    fn new() -> Self {
+        Self {
+            numeric: Keypad::numeric(),
+            directional: Keypad::directional(),
+            memo: HashMap::new(),
+        }
+    }
+
+    /// The human's presses needed to type `code` on the numeric keypad, with `robots` directional keypads
+    /// operated by robots in between.
+    fn code_cost(&mut self, code: &str, robots: usize) -> u64 {
+        let mut from = 'A';
+        let mut ret = 0;
+        for to in code.chars() {
+            let sequences = self.numeric.sequences(from, to);
+            ret += sequences
+                .iter()
+                .map(|s| self.sequence_cost(s, robots))
+                .min()
+                .unwrap();
+            from = to;
+        }
+        ret
+    }
+
+    /// The human's presses needed to enter `sequence` on the first of `robots` directional keypads.
+    fn sequence_cost(&mut self, sequence: &str, robots: usize) -> u64 {
+        if robots == 0 {
+            // typed by the human directly
+            return sequence.len() as u64;
+        }
+
+        let mut from = 'A';
+        let mut ret = 0;
+        for to in sequence.chars() {
+            ret += self.move_cost(from, to, robots);
+            from = to;
+        }
+        ret
+    }
+
+    fn move_cost(&mut self, from: char, to: char, robots: usize) -> u64 {
+        if let Some(cost) = self.memo.get(&(from, to, robots)) {
+            return *cost;
+        }
+
+        let sequences = self.directional.sequences(from, to);
+        let cost = sequences
+            .iter()
+            .map(|s| self.sequence_cost(s, robots - 1))
+            .min()
+            .unwrap();
+        self.memo.insert((from, to, robots), cost);
+        cost
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    parse::lines(Day21::DAY, input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            let code = line.text.trim();
+            if !code.chars().all(|c| c.is_ascii_digit() || c == 'A') {
+                return Err(line.error("a code of digits and `A`"));
+            }
+            Ok(code.to_owned())
+        })
+        .collect()
+}
+
+/// The numeric part of a code, ignoring leading zeroes, e.g. `29` for `029A`.
+pub fn numeric_part(code: &str) -> u32 {
+    code.chars()
+        .skip_while(|c| c.is_alphabetic() || *c == '0')
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse::<u32>()
+        .unwrap_or(0)
+}
+
+fn complexity(codes: &[String], robots: usize) -> u64 {
+    let mut chain = Chain::new();
+    codes
+        .iter()
+        .map(|code| chain.code_cost(code, robots) * numeric_part(code) as u64)
+        .sum()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(codes: &Self::Input) -> Answer {
+        complexity(codes, 2).into()
+    }
+
+    fn part2(codes: &Self::Input) -> Answer {
+        complexity(codes, 25).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day21: example);
+
+    #[test]
+    fn test_example() {
+        let codes = parse("029A\n980A\n179A\n456A\n379A\n").unwrap();
+        let mut chain = Chain::new();
+        assert_eq!(chain.code_cost("029A", 0), "<A^A>^^AvvvA".len() as u64);
+        assert_eq!(chain.code_cost("029A", 2), 68);
+        assert_eq!(complexity(&codes, 2), 126384);
+    }
+
+    #[test]
+    fn test_gap_is_avoided() {
+        let numeric = Keypad::numeric();
+        assert_eq!(numeric.sequences('A', '1'), vec!["^<<A"]);
+        assert_eq!(numeric.sequences('7', '0'), vec![">vvvA"]);
+        let directional = Keypad::directional();
+        assert_eq!(directional.sequences('<', '^'), vec![">^A"]);
+        assert_eq!(directional.sequences('A', 'v'), vec!["<vA", "v<A"]);
+    }
+}
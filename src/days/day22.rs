@@ -0,0 +1,403 @@
+use std::{
+    fmt::{self, Display},
+    ops::AddAssign,
+};
+
+use crate::{
+    parallel,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn mix(a: u64, b: u64) -> u64 {
+    a ^ b
+}
+
+fn prune(x: u64) -> u64 {
+    x % 16777216
+}
+
+fn next_number(input: u64) -> u64 {
+    let input = prune(mix(input, input * 64));
+    let input = prune(mix(input / 32, input));
+    prune(mix(input, 2048 * input))
+}
+
+fn part1(secret_numbers: &[u64]) -> u64 {
+    parallel::sum(secret_numbers, |number| {
+        let mut number = *number;
+        for _ in 0..SECRETS_GENERATED {
+            number = next_number(number);
+        }
+        number
+    })
+}
+
+type Sequence = [i32; 4];
+
+fn compute_prices(mut secret_number: u64, num_secrets_generated: usize) -> Vec<u32> {
+    std::iter::once((secret_number % 10) as u32)
+        .chain(std::iter::repeat_with(move || {
+            secret_number = next_number(secret_number);
+            (secret_number % 10) as u32
+        }))
+        .take(num_secrets_generated + 1)
+        .collect()
+}
+
+/// Number of possible sequences of four price changes, each in `-9..=9`.
+const SEQUENCE_COUNT: usize = 19 * 19 * 19 * 19;
+
+/// Buyers beyond this could overflow a `u16` table entry, as every buyer adds at most 9 bananas.
+const MAX_BUYERS: usize = u16::MAX as usize / 9;
+
+/// Appends a price change to an encoded sequence, dropping its oldest change.
+fn push_change(index: usize, change: i32) -> usize {
+    (index * 19 + (change + 9) as usize) % SEQUENCE_COUNT
+}
+
+fn encode(sequence: Sequence) -> usize {
+    sequence.into_iter().fold(0, push_change)
+}
+
+fn decode(mut index: usize) -> Sequence {
+    let mut sequence = [0; 4];
+    for change in sequence.iter_mut().rev() {
+        *change = (index % 19) as i32 - 9;
+        index /= 19;
+    }
+    sequence
+}
+
+/// A count of bananas in a [`PriceTable`]. `u16` keeps the table small enough to stay in cache,
+/// `u32` is only needed for more than [`MAX_BUYERS`] buyers.
+trait Bananas: Copy + Default + Ord + AddAssign + From<u16> + Into<u32> + Send {}
+
+impl Bananas for u16 {}
+
+impl Bananas for u32 {}
+
+/// Bananas earned per sequence of price changes, summed over buyers, indexed by [`encode`].
+struct PriceTable<B> {
+    bananas: Vec<B>,
+    /// The generation of the last buyer that sold at each sequence, 0 if none did.
+    seen: Vec<u32>,
+    generation: u32,
+}
+
+impl<B: Bananas> PriceTable<B> {
+    fn new() -> Self {
+        Self {
+            bananas: vec![B::default(); SEQUENCE_COUNT],
+            seen: vec![0; SEQUENCE_COUNT],
+            generation: 0,
+        }
+    }
+
+    /// Adds a buyer, who sells at the first occurrence of each sequence.
+    fn add_buyer(&mut self, secret_number: u64, num_secrets_generated: usize) {
+        self.generation += 1;
+        let prices = compute_prices(secret_number, num_secrets_generated);
+        let mut index = 0;
+        for (i, pair) in prices.windows(2).enumerate() {
+            index = push_change(index, pair[1] as i32 - pair[0] as i32);
+            // four consecutive changes are required
+            if i >= 3 && self.seen[index] != self.generation {
+                self.seen[index] = self.generation;
+                self.bananas[index] += B::from(pair[1] as u16);
+            }
+        }
+    }
+
+    /// Bananas earned with `sequence`, or `None` if no buyer sells at it.
+    fn get(&self, sequence: Sequence) -> Option<u32> {
+        let index = encode(sequence);
+        (self.seen[index] != 0).then(|| self.bananas[index].into())
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        for (a, b) in self.bananas.iter_mut().zip(&other.bananas) {
+            *a += *b;
+        }
+        // only whether a sequence was seen matters from here on, the next generation is new
+        for (a, b) in self.seen.iter_mut().zip(&other.seen) {
+            *a = u32::max(*a, *b);
+        }
+        self.generation = u32::max(self.generation, other.generation);
+        self
+    }
+}
+
+fn price_table<B: Bananas>(secret_numbers: &[u64], num_secrets_generated: usize) -> PriceTable<B> {
+    parallel::map_reduce(
+        secret_numbers,
+        PriceTable::new,
+        |mut table, secret_number| {
+            table.add_buyer(*secret_number, num_secrets_generated);
+            table
+        },
+        PriceTable::merge,
+    )
+}
+
+fn part2(secret_numbers: &[u64], num_secrets_generated: usize) -> u32 {
+    if secret_numbers.len() <= MAX_BUYERS {
+        find_best_sequence(&price_table::<u16>(secret_numbers, num_secrets_generated)).1
+    } else {
+        find_best_sequence(&price_table::<u32>(secret_numbers, num_secrets_generated)).1
+    }
+}
+
+/// The sequence earning the most bananas, preferring the lowest encoding on ties.
+fn find_best_sequence<B: Bananas>(table: &PriceTable<B>) -> (Sequence, u32) {
+    let (index, bananas) = table
+        .bananas
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, bananas)| **bananas)
+        .unwrap();
+    (decode(index), (*bananas).into())
+}
+
+fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse::lines(Day22::DAY, input)
+        .map(|line| line.parse(line.text, "a secret number"))
+        .collect()
+}
+
+/// When a buyer sells: after `step` new secret numbers, for `price` bananas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sale {
+    pub step: usize,
+    pub price: u32,
+}
+
+/// The first sale of a buyer with a given sequence of price changes, if it ever occurs.
+fn first_sale(
+    secret_number: u64,
+    num_secrets_generated: usize,
+    sequence: Sequence,
+) -> Option<Sale> {
+    let target = encode(sequence);
+    let prices = compute_prices(secret_number, num_secrets_generated);
+    let mut index = 0;
+    for (i, pair) in prices.windows(2).enumerate() {
+        index = push_change(index, pair[1] as i32 - pair[0] as i32);
+        if i >= 3 && index == target {
+            return Some(Sale {
+                step: i + 1,
+                price: pair[1],
+            });
+        }
+    }
+    None
+}
+
+/// How part 2's answer comes about, for checking it by hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub sequence: Sequence,
+    pub bananas: u32,
+    /// Each buyer's initial secret number and their sale with the winning sequence.
+    pub sales: Vec<(u64, Option<Sale>)>,
+    /// The next best sequences, best first.
+    pub alternatives: Vec<(Sequence, u32)>,
+}
+
+/// Explains part 2 with the `top` best alternatives to the winning sequence.
+pub fn explain(secret_numbers: &[u64], num_secrets_generated: usize, top: usize) -> Explanation {
+    if secret_numbers.len() <= MAX_BUYERS {
+        let table = price_table::<u16>(secret_numbers, num_secrets_generated);
+        explain_table(&table, secret_numbers, num_secrets_generated, top)
+    } else {
+        let table = price_table::<u32>(secret_numbers, num_secrets_generated);
+        explain_table(&table, secret_numbers, num_secrets_generated, top)
+    }
+}
+
+fn explain_table<B: Bananas>(
+    table: &PriceTable<B>,
+    secret_numbers: &[u64],
+    num_secrets_generated: usize,
+    top: usize,
+) -> Explanation {
+    let (sequence, bananas) = find_best_sequence(table);
+    let sales = secret_numbers
+        .iter()
+        .map(|secret_number| {
+            let sale = first_sale(*secret_number, num_secrets_generated, sequence);
+            (*secret_number, sale)
+        })
+        .collect();
+
+    let mut ranked = (0..SEQUENCE_COUNT)
+        .map(decode)
+        .filter_map(|sequence| Some((sequence, table.get(sequence)?)))
+        .collect::<Vec<_>>();
+    // same order as `find_best_sequence`, since sequences are ordered like their encodings, so
+    // the winner comes first
+    ranked.sort_by_key(|(sequence, bananas)| (std::cmp::Reverse(*bananas), *sequence));
+    let alternatives = ranked.into_iter().skip(1).take(top).collect();
+
+    Explanation {
+        sequence,
+        bananas,
+        sales,
+        alternatives,
+    }
+}
+
+fn format_sequence(sequence: Sequence) -> String {
+    sequence.map(|change| change.to_string()).join(",")
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "best sequence: {} ({} bananas)",
+            format_sequence(self.sequence),
+            self.bananas
+        )?;
+        for (secret_number, sale) in &self.sales {
+            match sale {
+                Some(Sale { step, price }) => {
+                    writeln!(f, "  {secret_number}: sells for {price} at step {step}")?
+                }
+                None => writeln!(f, "  {secret_number}: doesn't sell")?,
+            }
+        }
+        write!(f, "alternatives:")?;
+        for (sequence, bananas) in &self.alternatives {
+            write!(f, "\n  {}: {bananas}", format_sequence(*sequence))?;
+        }
+        Ok(())
+    }
+}
+
+/// Each buyer's number of new secret numbers per day.
+pub const SECRETS_GENERATED: usize = 2000;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(secret_numbers: &Self::Input) -> Answer {
+        part1(secret_numbers).into()
+    }
+
+    fn part2(secret_numbers: &Self::Input) -> Answer {
+        part2(secret_numbers, SECRETS_GENERATED).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day22: bananas, secrets);
+
+    fn sequence_map(secret_number: u64, num_secrets_generated: usize) -> PriceTable<u16> {
+        let mut table = PriceTable::new();
+        table.add_buyer(secret_number, num_secrets_generated);
+        table
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(encode([-9, -9, -9, -9]), 0);
+        assert_eq!(encode([9, 9, 9, 9]), SEQUENCE_COUNT - 1);
+        for sequence in [[-2, 1, -1, 3], [0, 0, 0, 0], [9, -9, 5, -1]] {
+            assert_eq!(decode(encode(sequence)), sequence);
+        }
+    }
+
+    #[test]
+    fn test_prune() {
+        assert_eq!(prune(100000000), 16113920);
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(mix(42, 15), 37);
+    }
+
+    #[test]
+    fn test_example() {
+        let expected = [
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254,
+        ];
+
+        let mut current = 123;
+        for expected in expected {
+            current = next_number(current);
+            assert_eq!(current, expected);
+        }
+    }
+
+    #[test]
+    fn test_sequence_prices() {
+        let secret_number = 123;
+        let prices = compute_prices(secret_number, 9);
+        assert_eq!(&prices, &[3, 0, 6, 5, 4, 4, 6, 4, 4, 2]);
+
+        let sequence_prices = sequence_map(123, 10);
+        let best = find_best_sequence(&sequence_prices);
+        assert_eq!(best, ([-1, -1, 0, 2], 6));
+    }
+
+    #[test]
+    fn test_part2() {
+        let secret_numbers = [1, 2, 3, 2024u64];
+        let num_secrets_generated = 2000;
+        let sequence_maps = secret_numbers.map(|s| sequence_map(s, num_secrets_generated));
+        let desired_sequence = [-2, 1, -1, 3];
+        assert_eq!(sequence_maps[0].get(desired_sequence), Some(7));
+        assert_eq!(sequence_maps[1].get(desired_sequence), Some(7));
+        assert_eq!(sequence_maps[2].get(desired_sequence), None);
+        assert_eq!(sequence_maps[3].get(desired_sequence), Some(9));
+        let result = part2(&secret_numbers, num_secrets_generated);
+        assert_eq!(result, 23);
+    }
+
+    #[test]
+    fn test_many_buyers() {
+        // every buyer sells at the same sequence, for more bananas than a `u16` holds
+        let secret_numbers = vec![2024; MAX_BUYERS + 1];
+        let best = part2(&secret_numbers, 50);
+        let single = part2(&[2024], 50);
+        assert_eq!(best, single * (MAX_BUYERS as u32 + 1));
+        assert!(best > u16::MAX as u32);
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = explain(&[1, 2, 3, 2024], 2000, 3);
+        assert_eq!(explanation.sequence, [-2, 1, -1, 3]);
+        assert_eq!(explanation.bananas, 23);
+        let prices = explanation
+            .sales
+            .iter()
+            .map(|(_, sale)| sale.map(|s| s.price))
+            .collect::<Vec<_>>();
+        assert_eq!(prices, vec![Some(7), Some(7), None, Some(9)]);
+        assert_eq!(explanation.alternatives.len(), 3);
+        assert!(explanation
+            .alternatives
+            .windows(2)
+            .all(|pair| pair[0].1 >= pair[1].1 && pair[0].1 <= 23));
+
+        let text = explanation.to_string();
+        assert!(text.starts_with("best sequence: -2,1,-1,3 (23 bananas)\n  1: sells for 7 at step"));
+        assert!(text.contains("\n  3: doesn't sell\n"));
+    }
+}
@@ -1,30 +1,30 @@
 use std::collections::{HashMap, HashSet};
 
-#[derive(Debug, Default)]
-struct NodeInfo {
-    index: u32,
-    edges: HashSet<u32>,
-}
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
 
 #[derive(Debug)]
-struct Graph {
+pub struct Graph {
     num_nodes: u32,
     edges: HashMap<u32, Vec<u32>>,
     name_per_node: HashMap<u32, String>,
 }
 
-fn parse(input: &str) -> Graph {
+fn parse(input: &str) -> Result<Graph, ParseError> {
     let mut nodes = HashSet::new();
     let mut edges_str: HashMap<String, Vec<String>> = HashMap::new();
-    input.lines().for_each(|line| {
-        let line = line.trim();
-        if line.is_empty() {
-            return;
+    for line in parse::lines(Day23::DAY, input) {
+        let text = line.text.trim();
+        if text.is_empty() {
+            continue;
         }
 
-        let mut it = line.split("-");
-        let a = it.next().unwrap().to_owned();
-        let b = it.next().unwrap().to_owned();
+        let Some((a, b)) = text.split_once("-") else {
+            return Err(line.error_at(text, "`a-b`"));
+        };
+        let (a, b) = (a.to_owned(), b.to_owned());
         nodes.insert(a.clone());
         nodes.insert(b.clone());
 
@@ -32,7 +32,7 @@ fn parse(input: &str) -> Graph {
         edges_a.push(b.clone());
         let edges_b = edges_str.entry(b).or_default();
         edges_b.push(a);
-    });
+    }
 
     let mut nodes_sorted = nodes.iter().collect::<Vec<_>>();
     nodes_sorted.sort();
@@ -41,7 +41,7 @@ fn parse(input: &str) -> Graph {
     let mut name_per_idx = HashMap::new();
     for (i, n) in nodes_sorted.into_iter().enumerate() {
         idx_per_name.insert(n.clone(), i as u32);
-        name_per_idx.insert(i as u32 as u32, n.clone());
+        name_per_idx.insert(i as u32, n.clone());
     }
 
     let mut edges_normalized = HashMap::new();
@@ -52,11 +52,11 @@ fn parse(input: &str) -> Graph {
         edges_normalized.insert(idx_per_name[n], edges);
     }
 
-    Graph {
+    Ok(Graph {
         num_nodes: name_per_idx.len() as u32,
         edges: edges_normalized,
         name_per_node: name_per_idx,
-    }
+    })
 }
 
 fn part1(graph: &Graph) -> u32 {
@@ -74,7 +74,7 @@ fn part1(graph: &Graph) -> u32 {
                     if graph.name_per_node[v].starts_with("t") && *v < current_node {
                         continue;
                     }
-                    if graph.edges[&u].contains(&v) {
+                    if graph.edges[&u].contains(v) {
                         // because current_node < u, and edges are sorted, we are good to count this triple
                         count += 1;
                     }
@@ -161,60 +161,29 @@ fn part2(graph: &Graph) -> String {
     output
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/day23.txt").unwrap();
-    let graph = parse(&input);
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input = Graph;
 
-    let part1 = part1(&graph);
-    println!("part 1: {part1}");
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Answer {
+        part1(graph).into()
+    }
 
-    let part2 = part2(&graph);
-    println!("part 2: {part2:?}");
+    fn part2(graph: &Self::Input) -> Answer {
+        part2(graph).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    static EXAMPLE: &str = r#"
-        kh-tc
-        qp-kh
-        de-cg
-        ka-co
-        yn-aq
-        qp-ub
-        cg-tb
-        vc-aq
-        tb-ka
-        wh-tc
-        yn-cg
-        kh-ub
-        ta-co
-        de-co
-        tc-td
-        tb-wq
-        wh-td
-        ta-ka
-        td-qp
-        aq-cg
-        wq-ub
-        ub-vc
-        de-ta
-        wq-aq
-        wq-vc
-        wh-yn
-        ka-de
-        kh-ta
-        co-tc
-        wh-qp
-        tb-vc
-        td-yn
-        "#;
-
-    #[test]
-    fn example() {
-        let graph = parse(EXAMPLE);
-        let part1 = part1(&graph);
-        assert_eq!(part1, 7);
-    }
+    crate::example_tests!(Day23: example);
 }
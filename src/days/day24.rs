@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a && b,
+            Op::Or => a || b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Gate {
+    inputs: [String; 2],
+    op: Op,
+    output: String,
+}
+
+impl Gate {
+    /// Whether this gate reads the input bits `x`/`y` directly.
+    fn reads_inputs(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|wire| wire.starts_with('x') || wire.starts_with('y'))
+    }
+
+    fn reads_first_bit(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|wire| wire == "x00" || wire == "y00")
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    initial: HashMap<String, bool>,
+    gates: Vec<Gate>,
+    /// Indices of `gates` in an order where every gate comes after the gates producing its inputs.
+    order: Vec<usize>,
+}
+
+fn parse(input: &str) -> Result<Input, ParseError> {
+    let mut lines = parse::lines(Day24::DAY, input);
+
+    let mut initial = HashMap::new();
+    for line in lines
+        .by_ref()
+        .take_while(|line| !line.text.trim().is_empty())
+    {
+        let Some((wire, value)) = line.text.split_once(": ") else {
+            return Err(line.error("`<wire>: <0|1>`"));
+        };
+        let value = match value.trim() {
+            "0" => false,
+            "1" => true,
+            _ => return Err(line.error_at(value, "`0` or `1`")),
+        };
+        initial.insert(wire.to_owned(), value);
+    }
+
+    let mut gates = vec![];
+    let mut gate_lines = vec![];
+    for line in lines {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        let mut tokens = line.text.split_whitespace();
+        let a = line.next(&mut tokens, "a wire")?;
+        let op = line.next(&mut tokens, "`AND`, `OR` or `XOR`")?;
+        let op = match op {
+            "AND" => Op::And,
+            "OR" => Op::Or,
+            "XOR" => Op::Xor,
+            _ => return Err(line.error_at(op, "`AND`, `OR` or `XOR`")),
+        };
+        let b = line.next(&mut tokens, "a wire")?;
+        let arrow = line.next(&mut tokens, "`->`")?;
+        if arrow != "->" {
+            return Err(line.error_at(arrow, "`->`"));
+        }
+        let output = line.next(&mut tokens, "a wire")?;
+
+        gates.push(Gate {
+            inputs: [a.to_owned(), b.to_owned()],
+            op,
+            output: output.to_owned(),
+        });
+        gate_lines.push((line, [a, b]));
+    }
+
+    let outputs = gates
+        .iter()
+        .map(|gate| gate.output.as_str())
+        .collect::<HashSet<_>>();
+    for (line, inputs) in &gate_lines {
+        if let Some(wire) = inputs
+            .iter()
+            .find(|wire| !initial.contains_key(**wire) && !outputs.contains(**wire))
+        {
+            return Err(line.error_at(wire, "a wire that is set or produced by a gate"));
+        }
+    }
+
+    let order = topological_order(&gates)
+        .map_err(|i| gate_lines[i].0.error("a gate that isn't part of a cycle"))?;
+
+    Ok(Input {
+        initial,
+        gates,
+        order,
+    })
+}
+
+/// The gates ordered so each one comes after the gates producing its inputs. If the wires form a cycle,
+/// returns a gate that is on the cycle or depends on it instead.
+fn topological_order(gates: &[Gate]) -> Result<Vec<usize>, usize> {
+    let producer = gates
+        .iter()
+        .enumerate()
+        .map(|(i, gate)| (gate.output.as_str(), i))
+        .collect::<HashMap<_, _>>();
+
+    let mut consumers: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut pending_inputs = vec![0; gates.len()];
+    for (i, gate) in gates.iter().enumerate() {
+        for wire in &gate.inputs {
+            if let Some(&p) = producer.get(wire.as_str()) {
+                consumers.entry(p).or_default().push(i);
+                pending_inputs[i] += 1;
+            }
+        }
+    }
+
+    let mut ready = (0..gates.len())
+        .filter(|i| pending_inputs[*i] == 0)
+        .collect::<Vec<_>>();
+    let mut ret = vec![];
+    while let Some(i) = ready.pop() {
+        ret.push(i);
+        for c in consumers.get(&i).into_iter().flatten() {
+            pending_inputs[*c] -= 1;
+            if pending_inputs[*c] == 0 {
+                ready.push(*c);
+            }
+        }
+    }
+
+    match pending_inputs.iter().position(|pending| *pending > 0) {
+        Some(i) => Err(i),
+        None => Ok(ret),
+    }
+}
+
+/// The number formed by the `z` wires, `z00` being the least significant bit.
+fn part1(input: &Input) -> u64 {
+    let mut values = input.initial.clone();
+    for &i in &input.order {
+        let gate = &input.gates[i];
+        let [a, b] = &gate.inputs;
+        let value = gate.op.apply(values[a], values[b]);
+        values.insert(gate.output.clone(), value);
+    }
+
+    let mut z_wires = values
+        .iter()
+        .filter(|(wire, _)| wire.starts_with('z'))
+        .collect::<Vec<_>>();
+    z_wires.sort();
+    z_wires
+        .into_iter()
+        .rev()
+        .fold(0, |acc, (_, value)| acc << 1 | *value as u64)
+}
+
+/// The outputs that break the structure of a ripple-carry adder, sorted and comma-separated.
+///
+/// For every bit `i > 0`, the adder computes
+/// - `x_i XOR y_i -> s_i` and `x_i AND y_i -> c1_i`,
+/// - `s_i XOR carry_i -> z_i` and `s_i AND carry_i -> c2_i`,
+/// - `c1_i OR c2_i -> carry_(i+1)`,
+///
+/// with `x00 XOR y00 -> z00` and `x00 AND y00 -> carry_1`, and the last carry being the highest `z`.
+/// Rather than reconstructing the adder, each gate is checked against the rules this implies.
+fn part2(input: &Input) -> String {
+    let last_z = input
+        .gates
+        .iter()
+        .map(|gate| &gate.output)
+        .filter(|wire| wire.starts_with('z'))
+        .max()
+        .cloned()
+        .unwrap_or_default();
+
+    // the ops of all gates reading each wire
+    let mut consumer_ops: HashMap<&str, HashSet<Op>> = HashMap::new();
+    for gate in &input.gates {
+        for wire in &gate.inputs {
+            consumer_ops.entry(wire).or_default().insert(gate.op);
+        }
+    }
+    let read_by = |wire: &str, op| consumer_ops.get(wire).is_some_and(|ops| ops.contains(&op));
+
+    let mut swapped = input
+        .gates
+        .iter()
+        .filter(|gate| {
+            let output = gate.output.as_str();
+            if output.starts_with('z') {
+                // every z is a sum bit, except the final carry
+                return if output == last_z {
+                    gate.op != Op::Or
+                } else {
+                    gate.op != Op::Xor
+                };
+            }
+            match gate.op {
+                // the second XOR of each bit produces a z, so the first one must feed it
+                Op::Xor => !gate.reads_inputs() || !read_by(output, Op::Xor),
+                // partial carries are combined by an OR, apart from the first bit's carry
+                Op::And => !gate.reads_first_bit() && !read_by(output, Op::Or),
+                // carries feed the next bit's XOR and AND
+                Op::Or => !read_by(output, Op::Xor) || !read_by(output, Op::And),
+            }
+        })
+        .map(|gate| gate.output.clone())
+        .collect::<Vec<_>>();
+
+    swapped.sort();
+    swapped.join(",")
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day24: large, small);
+
+    /// A ripple-carry adder over `bits` bits, with the given pairs of gate outputs swapped.
+    fn adder(bits: u32, x: u64, y: u64, swaps: &[(&str, &str)]) -> String {
+        let mut ret = String::new();
+        for i in 0..bits {
+            ret += &format!("x{i:02}: {}\n", x >> i & 1);
+        }
+        for i in 0..bits {
+            ret += &format!("y{i:02}: {}\n", y >> i & 1);
+        }
+        ret += "\n";
+
+        let mut gates = vec![
+            ("x00 XOR y00".to_owned(), "z00".to_owned()),
+            ("x00 AND y00".to_owned(), "c01".to_owned()),
+        ];
+        for i in 1..bits {
+            let carry_out = if i + 1 == bits {
+                format!("z{bits:02}")
+            } else {
+                format!("c{:02}", i + 1)
+            };
+            gates.extend([
+                (format!("x{i:02} XOR y{i:02}"), format!("s{i:02}")),
+                (format!("x{i:02} AND y{i:02}"), format!("a{i:02}")),
+                (format!("s{i:02} XOR c{i:02}"), format!("z{i:02}")),
+                (format!("c{i:02} AND s{i:02}"), format!("b{i:02}")),
+                (format!("a{i:02} OR b{i:02}"), carry_out),
+            ]);
+        }
+        for (expression, output) in gates {
+            let mut output = output.as_str();
+            for (a, b) in swaps {
+                if output == *a {
+                    output = b;
+                } else if output == *b {
+                    output = a;
+                }
+            }
+            ret += &format!("{expression} -> {output}\n");
+        }
+        ret
+    }
+
+    #[test]
+    fn test_example() {
+        let input = parse(
+            "x00: 1\nx01: 1\nx02: 1\ny00: 0\ny01: 1\ny02: 0\n\n\
+            x00 AND y00 -> z00\nx01 XOR y01 -> z01\nx02 OR y02 -> z02\n",
+        )
+        .unwrap();
+        assert_eq!(part1(&input), 4);
+    }
+
+    #[test]
+    fn test_adder() {
+        let input = parse(&adder(8, 200, 100, &[])).unwrap();
+        assert_eq!(part1(&input), 300);
+        assert_eq!(part2(&input), "");
+
+        let swaps = [("s03", "a03"), ("z05", "b05"), ("c07", "z06")];
+        let input = parse(&adder(8, 200, 100, &swaps)).unwrap();
+        assert_eq!(part2(&input), "a03,b05,c07,s03,z05,z06");
+    }
+
+    #[test]
+    fn invalid_circuits() {
+        let err = parse("x00: 1\n\nx00 AND y00 -> z00\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (3, 9, "y00"));
+
+        let err = parse("x00: 1\n\nx00 OR z01 -> z00\nx00 AND z00 -> z01\nx00 OR x00 -> z02\n")
+            .unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(err.expected, "a gate that isn't part of a cycle");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+    parse::{self, Line, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Lock {
+    lengths: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub struct Key {
+    lengths: Vec<u32>,
+}
+
+fn parse_locks_and_keys(text: &str) -> Result<(Vec<Lock>, Vec<Key>, u32), ParseError> {
+    let mut locks = vec![];
+    let mut keys = vec![];
+
+    let mut current_lines: Vec<Line> = vec![];
+    // every schematic must have the size of the first one
+    let mut size: Option<UIndex2> = None;
+    let mut add_schematic = |lines: &mut Vec<Line>| -> Result<(), ParseError> {
+        let (first, last) = (lines[0], lines[lines.len() - 1]);
+        let grid = Grid::from_lines_with(lines.drain(..), |c, _| match c {
+            '#' | '.' => Ok(c),
+            _ => Err("`#` or `.`"),
+        })?;
+        let dimension = grid.dimension();
+        let expected = *size.get_or_insert(dimension);
+        if dimension.x != expected.x {
+            return Err(first.error(format!("a row of width {}", expected.x)));
+        }
+        if dimension.y != expected.y {
+            return Err(first.error(format!("a schematic of height {}", expected.y)));
+        }
+
+        let is_lock = grid[uidx2(0, 0)] == '#';
+        // the lock's top row or the key's bottom row
+        let base = if is_lock { first } else { last };
+        if !base.text.chars().all(|c| c == '#') {
+            return Err(base.error("a row of `#`"));
+        }
+        let lengths = extract_lens(&grid);
+        if is_lock {
+            locks.push(Lock { lengths });
+        } else {
+            keys.push(Key { lengths });
+        }
+        Ok(())
+    };
+
+    for line in parse::lines(Day25::DAY, text) {
+        let text = line.text.trim();
+        if text.is_empty() {
+            if !current_lines.is_empty() {
+                add_schematic(&mut current_lines)?;
+            }
+            continue;
+        }
+
+        current_lines.push(Line { text, ..line });
+    }
+    // the last schematic isn't followed by a blank line
+    if !current_lines.is_empty() {
+        add_schematic(&mut current_lines)?;
+    }
+
+    Ok((locks, keys, size.map_or(0, |size| size.y)))
+}
+
+fn extract_lens(grid: &Grid<char>) -> Vec<u32> {
+    // convert the schematic to lock or key
+    let dimension = grid.dimension();
+    let is_lock = grid[uidx2(0, 0)] == '#';
+
+    let mut lengths = vec![];
+
+    let rows_in_order = if is_lock {
+        (0..dimension.y).collect::<Vec<_>>()
+    } else {
+        (0..dimension.y).rev().collect::<Vec<_>>()
+    };
+
+    for column in 0..dimension.x {
+        let mut len = 0;
+        for row in rows_in_order.iter() {
+            let idx = uidx2(column, *row);
+            if grid[idx] == '.' {
+                break;
+            } else {
+                len += 1;
+            }
+        }
+        lengths.push(len - 1); // base level not counted in examples
+    }
+
+    lengths
+}
+
+fn part1(locks: &[Lock], keys: &[Key], max_len: u32) -> u32 {
+    let mut ret = 0;
+    for lock in locks {
+        for key in keys {
+            if check_fits(lock, key, max_len) {
+                ret += 1;
+            }
+        }
+    }
+
+    ret
+}
+
+fn check_fits(lock: &Lock, key: &Key, max_len: u32) -> bool {
+    for column in 0..lock.lengths.len() {
+        let a = lock.lengths[column];
+        let b = key.lengths[column];
+        if a + b + 2 > max_len {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Input = (Vec<Lock>, Vec<Key>, u32);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_locks_and_keys(input)
+    }
+
+    fn part1((locks, keys, max_len): &Self::Input) -> Answer {
+        part1(locks, keys, *max_len).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day25: example);
+
+    #[test]
+    fn test_len_extract() {
+        let input = Grid::parse(Day25::DAY, "###\n##.\n#..\n...").unwrap();
+        let lengths = extract_lens(&input);
+        assert_eq!(lengths, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn malformed_schematics() {
+        let err = parse_locks_and_keys("#.###\n.....\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (1, "a row of `#`"));
+
+        let err = parse_locks_and_keys(".....\n##.##\n").unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (2, "##.##"));
+
+        let err = parse_locks_and_keys("###\n...\n\n....\n####\n").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (4, "a row of width 3"));
+
+        let err = parse_locks_and_keys("###\n...\n\n...\n...\n###\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.expected.as_str()),
+            (4, "a schematic of height 2")
+        );
+    }
+}
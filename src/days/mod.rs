@@ -0,0 +1,60 @@
+use crate::solution::RegisteredSolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every implemented day, in calendar order.
+pub const ALL: &[RegisteredSolution] = &[
+    RegisteredSolution::new::<day01::Day01>(),
+    RegisteredSolution::new::<day02::Day02>(),
+    RegisteredSolution::new::<day03::Day03>(),
+    RegisteredSolution::new::<day04::Day04>(),
+    RegisteredSolution::new::<day05::Day05>(),
+    RegisteredSolution::new::<day06::Day06>(),
+    RegisteredSolution::new::<day07::Day07>(),
+    RegisteredSolution::new::<day08::Day08>(),
+    RegisteredSolution::new::<day09::Day09>(),
+    RegisteredSolution::new::<day10::Day10>(),
+    RegisteredSolution::new::<day11::Day11>(),
+    RegisteredSolution::new::<day12::Day12>(),
+    RegisteredSolution::new::<day13::Day13>(),
+    RegisteredSolution::new::<day14::Day14>(),
+    RegisteredSolution::new::<day15::Day15>(),
+    RegisteredSolution::new::<day16::Day16>(),
+    RegisteredSolution::new::<day17::Day17>(),
+    RegisteredSolution::new::<day18::Day18>(),
+    RegisteredSolution::new::<day19::Day19>(),
+    RegisteredSolution::new::<day20::Day20>(),
+    RegisteredSolution::new::<day21::Day21>(),
+    RegisteredSolution::new::<day22::Day22>(),
+    RegisteredSolution::new::<day23::Day23>(),
+    RegisteredSolution::new::<day24::Day24>(),
+    RegisteredSolution::new::<day25::Day25>(),
+];
+
+pub fn find(day: u32) -> Option<&'static RegisteredSolution> {
+    ALL.iter().find(|s| s.day == day)
+}
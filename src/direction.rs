@@ -1,6 +1,6 @@
 use crate::{
     grid::Grid,
-    index2::{uidx2, Index2, UIndex2},
+    index2::{Index2, UIndex2},
 };
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
@@ -23,6 +23,28 @@ impl Direction {
         }
     }
 
+    /// The direction of a unit offset, if it is one.
+    pub fn from_index2(offset: Index2) -> Option<Self> {
+        Direction::ALL
+            .iter()
+            .copied()
+            .find(|d| d.to_index2() == offset)
+    }
+
+    /// One of `^`, `v`, `<`, `>`.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    pub fn from_char(c: char) -> Option<Self> {
+        Direction::ALL.iter().copied().find(|d| d.to_char() == c)
+    }
+
     pub fn turn_clockwise(self) -> Self {
         match self {
             Direction::Up => Direction::Right,
@@ -32,6 +54,15 @@ impl Direction {
         }
     }
 
+    pub fn turn_counterclockwise(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+
     pub fn invert(self) -> Self {
         match self {
             Direction::Up => Direction::Down,
@@ -42,20 +73,168 @@ impl Direction {
     }
 
     pub fn get_neighbor<T>(self, idx: UIndex2, grid: &Grid<T>) -> Option<UIndex2> {
-        let offset = self.to_index2();
-        let target = idx.to_index2() + offset;
+        offset_in_grid(idx, self.to_index2(), grid)
+    }
+
+    pub fn offset_index(&self, idx: Index2) -> Index2 {
+        idx + self.to_index2()
+    }
+}
+
+/// A direction including diagonals, in clockwise order starting at `Up`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: &[Self] = &[
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub const DIAGONALS: &[Self] = &[Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    pub fn to_index2(self) -> Index2 {
+        match self {
+            Direction8::Up => Index2 { x: 0, y: -1 },
+            Direction8::UpRight => Index2 { x: 1, y: -1 },
+            Direction8::Right => Index2 { x: 1, y: 0 },
+            Direction8::DownRight => Index2 { x: 1, y: 1 },
+            Direction8::Down => Index2 { x: 0, y: 1 },
+            Direction8::DownLeft => Index2 { x: -1, y: 1 },
+            Direction8::Left => Index2 { x: -1, y: 0 },
+            Direction8::UpLeft => Index2 { x: -1, y: -1 },
+        }
+    }
+
+    /// The direction of a unit (or unit diagonal) offset, if it is one.
+    pub fn from_index2(offset: Index2) -> Option<Self> {
+        Direction8::ALL
+            .iter()
+            .copied()
+            .find(|d| d.to_index2() == offset)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.to_direction().is_none()
+    }
 
-        let Some(idx) = target.to_index2() else {
-            return None;
-        };
-        if grid.get(idx).is_some() {
-            Some(idx)
-        } else {
-            None
+    /// The matching 4-way direction, or `None` for diagonals.
+    pub fn to_direction(self) -> Option<Direction> {
+        match self {
+            Direction8::Up => Some(Direction::Up),
+            Direction8::Right => Some(Direction::Right),
+            Direction8::Down => Some(Direction::Down),
+            Direction8::Left => Some(Direction::Left),
+            _ => None,
         }
     }
 
+    /// Turns by `steps` times 45°, clockwise for positive `steps`.
+    pub fn rotate(self, steps: i32) -> Self {
+        let i = self as i32 + steps;
+        Direction8::ALL[i.rem_euclid(8) as usize]
+    }
+
+    /// Turns by 45°.
+    pub fn turn_clockwise(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turns by 45°.
+    pub fn turn_counterclockwise(self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn turn_clockwise_90(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_counterclockwise_90(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn invert(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn get_neighbor<T>(self, idx: UIndex2, grid: &Grid<T>) -> Option<UIndex2> {
+        offset_in_grid(idx, self.to_index2(), grid)
+    }
+
     pub fn offset_index(&self, idx: Index2) -> Index2 {
         idx + self.to_index2()
     }
 }
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+impl From<Direction> for Index2 {
+    fn from(direction: Direction) -> Self {
+        direction.to_index2()
+    }
+}
+
+impl From<Direction8> for Index2 {
+    fn from(direction: Direction8) -> Self {
+        direction.to_index2()
+    }
+}
+
+fn offset_in_grid<T>(idx: UIndex2, offset: Index2, grid: &Grid<T>) -> Option<UIndex2> {
+    let target = (idx.to_index2() + offset).to_index2()?;
+    grid.get(target).is_some().then_some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for d in Direction::ALL.iter().copied() {
+            assert_eq!(d.turn_clockwise().turn_counterclockwise(), d);
+            assert_eq!(d.turn_clockwise().turn_clockwise(), d.invert());
+            assert_eq!(Direction::from_char(d.to_char()), Some(d));
+            assert_eq!(Direction::from_index2(d.to_index2()), Some(d));
+            assert_eq!(
+                Direction8::from(d).turn_clockwise_90(),
+                Direction8::from(d.turn_clockwise())
+            );
+        }
+
+        assert_eq!(Direction8::Up.turn_clockwise(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_counterclockwise(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.invert(), Direction8::DownRight);
+        assert_eq!(Direction8::Left.rotate(-10), Direction8::Down);
+        assert_eq!(
+            Direction8::from_index2(Index2::new(-1, 1)),
+            Some(Direction8::DownLeft)
+        );
+        assert_eq!(Direction8::from_index2(Index2::new(2, 0)), None);
+        assert!(Direction8::DIAGONALS.iter().all(|d| d.is_diagonal()));
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+use crate::{
+    answers::{Answers, AnswersError},
+    solution::{Answer, Params, Part, RegisteredSolution, Solution},
+};
+
+/// Worked examples live in `examples/dayNN/NAME.txt`, with the expected answers in `NAME.toml`
+/// next to it, written like a single day of the answers file. Examples that are smaller than the
+/// real input override the day's [parameters](Solution::PARAMS) in a `params` table:
+///
+/// ```toml
+/// part1 = 11
+/// part2 = "a,b"
+///
+/// [params]
+/// size = 7
+/// ```
+pub const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+#[derive(Debug)]
+pub enum FixtureError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Answers {
+        path: PathBuf,
+        source: AnswersError,
+    },
+    /// The answers file lists neither part.
+    NoAnswers {
+        path: PathBuf,
+    },
+}
+
+impl Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureError::Io { path, source } => {
+                write!(f, "cannot read {}: {source}", path.display())
+            }
+            FixtureError::Answers { path, source } => write!(f, "{}: {source}", path.display()),
+            FixtureError::NoAnswers { path } => {
+                write!(f, "{} has neither `part1` nor `part2`", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FixtureError::Io { source, .. } => Some(source),
+            FixtureError::Answers { source, .. } => Some(source),
+            FixtureError::NoAnswers { .. } => None,
+        }
+    }
+}
+
+/// A worked example from a puzzle text and the answers it should produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u32,
+    pub name: String,
+    pub input: String,
+    pub expected: [Option<Answer>; 2],
+    pub params: Params,
+}
+
+impl Fixture {
+    pub fn dir(day: u32) -> PathBuf {
+        PathBuf::from(FIXTURE_DIR).join(format!("day{day:02}"))
+    }
+
+    pub fn load(day: u32, name: &str) -> Result<Self, FixtureError> {
+        let dir = Self::dir(day);
+        let input = read(dir.join(format!("{name}.txt")))?;
+        let path = dir.join(format!("{name}.toml"));
+        let answers_error = |source| FixtureError::Answers {
+            path: path.clone(),
+            source,
+        };
+        let mut table: toml::Table = toml::from_str(&read(path.clone())?)
+            .map_err(|e| answers_error(AnswersError::Toml(e)))?;
+        let params = match table.remove("params") {
+            Some(params) => params
+                .try_into()
+                .map_err(|e| answers_error(AnswersError::Toml(e)))?,
+            None => Params::new(),
+        };
+        let expected = Answers::day_from_table(table).map_err(answers_error)?;
+        if expected.iter().all(Option::is_none) {
+            return Err(FixtureError::NoAnswers { path });
+        }
+
+        Ok(Self {
+            day,
+            name: name.to_owned(),
+            input,
+            expected,
+            params,
+        })
+    }
+
+    /// Names of the fixtures stored for `day`, sorted.
+    pub fn names(day: u32) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(Self::dir(day)) else {
+            return vec![];
+        };
+        let mut names = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "txt" {
+                    return None;
+                }
+                path.file_stem()?.to_str().map(str::to_owned)
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    /// Runs the parts with an expected answer, returning a description of each mismatch.
+    pub fn check(&self, solution: &RegisteredSolution) -> Vec<String> {
+        let parts = Part::ALL
+            .iter()
+            .copied()
+            .filter(|part| self.expected(*part).is_some())
+            .collect::<Vec<_>>();
+        let results = match solution.run_with_params(&self.input, &parts, &self.params) {
+            Ok(results) => results,
+            Err(e) => return vec![e.to_string()],
+        };
+        results
+            .into_iter()
+            .filter_map(|result| {
+                let expected = self.expected(result.part)?;
+                (*expected != result.answer).then(|| {
+                    format!(
+                        "part {}: expected {expected}, got {}",
+                        result.part.number(),
+                        result.answer
+                    )
+                })
+            })
+            .collect()
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&Answer> {
+        self.expected[part.number() as usize - 1].as_ref()
+    }
+}
+
+fn read(path: PathBuf) -> Result<String, FixtureError> {
+    std::fs::read_to_string(&path).map_err(|source| FixtureError::Io { path, source })
+}
+
+/// Checks a single fixture of `S`, panicking on a mismatch. Used by [`example_tests!`](crate::example_tests).
+pub fn assert_example<S: Solution>(name: &str) {
+    let fixture = Fixture::load(S::DAY, name).unwrap_or_else(|e| panic!("{e}"));
+    let mismatches = fixture.check(&RegisteredSolution::new::<S>());
+    assert!(
+        mismatches.is_empty(),
+        "day {:02} example `{name}`:\n{}",
+        S::DAY,
+        mismatches.join("\n")
+    );
+}
+
+/// Checks that every fixture stored for `S` has a test. Used by [`example_tests!`](crate::example_tests).
+pub fn assert_examples_listed<S: Solution>(listed: &[&str]) {
+    let mut listed = listed.to_vec();
+    listed.sort();
+    assert_eq!(
+        Fixture::names(S::DAY),
+        listed,
+        "every fixture in {} must be listed in `example_tests!`",
+        Fixture::dir(S::DAY).display()
+    );
+}
+
+/// Generates a `#[test]` per named fixture of a solution, plus one that fails if a fixture exists
+/// without being listed.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     crate::example_tests!(Day01: example);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty: $($name:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::fixture::assert_example::<$solution>(stringify!($name));
+            }
+        )+
+
+        #[test]
+        fn all_examples_listed() {
+            $crate::fixture::assert_examples_listed::<$solution>(&[$(stringify!($name)),+]);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_fixture() {
+        let fixture = Fixture::load(3, "conditionals").unwrap();
+        assert_eq!(fixture.expected, [None, Some(Answer::Number(48))]);
+        assert_eq!(Fixture::names(3), vec!["conditionals", "mul"]);
+        assert!(Fixture::names(99).is_empty());
+        assert!(matches!(
+            Fixture::load(3, "missing"),
+            Err(FixtureError::Io { .. })
+        ));
+
+        let solution = crate::days::find(3).unwrap();
+        assert!(fixture.check(solution).is_empty());
+        let wrong = Fixture {
+            expected: [Some(Answer::Number(1)), None],
+            ..fixture
+        };
+        assert_eq!(wrong.check(solution), vec!["part 1: expected 1, got 161"]);
+
+        let unknown = Fixture {
+            params: Params::from([("size".to_owned(), 7)]),
+            ..wrong
+        };
+        assert_eq!(
+            unknown.check(solution),
+            vec!["part 1: expected 1, got <failed: day 03 has no parameter `size`>"]
+        );
+    }
+}
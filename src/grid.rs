@@ -1,4 +1,8 @@
-use super::index2::{uidx2, UIndex2};
+use super::{
+    direction::{Direction, Direction8},
+    index2::{uidx2, Index2, UIndex2},
+    parse::{self, Line, ParseError},
+};
 use serde::{Deserialize, Serialize};
 use std::ops::{Index, IndexMut};
 
@@ -54,6 +58,126 @@ impl<T> Grid<T> {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
         self.data.iter_mut()
     }
+
+    /// The positions reached by repeatedly stepping from `idx` (exclusive) until leaving the grid.
+    ///
+    /// `step` is a [`Direction`], a [`Direction8`] or any `Index2` offset. A zero offset never
+    /// leaves `idx`, so its ray is empty.
+    pub fn ray(
+        &self,
+        idx: UIndex2,
+        step: impl Into<Index2>,
+    ) -> impl Iterator<Item = (UIndex2, &T)> + '_ {
+        let step = step.into();
+        let mut current = idx.to_index2();
+        std::iter::from_fn(move || {
+            if step == Index2::zero() {
+                return None;
+            }
+            current += step;
+            let idx = current.to_index2()?;
+            self.get(idx).map(|tile| (idx, tile))
+        })
+    }
+
+    /// Like [`Grid::ray`], but stops after the first position for which `stop` returns true.
+    pub fn ray_until<'a, F>(
+        &'a self,
+        idx: UIndex2,
+        step: impl Into<Index2>,
+        mut stop: F,
+    ) -> impl Iterator<Item = (UIndex2, &'a T)> + 'a
+    where
+        F: FnMut(&T) -> bool + 'a,
+    {
+        let mut stopped = false;
+        self.ray(idx, step).take_while(move |(_, tile)| {
+            let take = !stopped;
+            stopped = stopped || stop(tile);
+            take
+        })
+    }
+
+    /// The in-bounds indices next to `idx`, horizontally or vertically.
+    pub fn neighbor_indices4(&self, idx: UIndex2) -> impl Iterator<Item = UIndex2> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |d| d.get_neighbor(idx, self))
+    }
+
+    /// The in-bounds indices next to `idx`, including diagonally.
+    pub fn neighbor_indices8(&self, idx: UIndex2) -> impl Iterator<Item = UIndex2> + '_ {
+        Direction8::ALL
+            .iter()
+            .filter_map(move |d| d.get_neighbor(idx, self))
+    }
+
+    /// The in-bounds cells next to `idx`, horizontally or vertically.
+    pub fn neighbors4(&self, idx: UIndex2) -> impl Iterator<Item = (UIndex2, &T)> + '_ {
+        self.neighbor_indices4(idx).map(|n| (n, &self[n]))
+    }
+
+    /// The in-bounds cells next to `idx`, including diagonally.
+    pub fn neighbors8(&self, idx: UIndex2) -> impl Iterator<Item = (UIndex2, &T)> + '_ {
+        self.neighbor_indices8(idx).map(|n| (n, &self[n]))
+    }
+
+    /// Builds a grid from text, one row per line, mapping each character through `f`.
+    ///
+    /// `f` receives the character and its position and returns the tile or a description of what was
+    /// expected instead. Since it can capture state, it can also record marker positions (e.g. a start
+    /// tile) while parsing. Rows must all have the same width; trailing blank lines are ignored.
+    pub fn from_str_with<E, F>(day: u32, input: &str, f: F) -> Result<Self, ParseError>
+    where
+        E: Into<String>,
+        F: FnMut(char, UIndex2) -> Result<T, E>,
+    {
+        // cut after the last row that isn't blank, keeping any spaces at its end
+        let last = input.trim_end().len();
+        let end = input[last..].find('\n').map_or(input.len(), |i| last + i);
+        Self::from_lines_with(parse::lines(day, &input[..end]), f)
+    }
+
+    /// Like [`Grid::from_str_with`], for grids that are only part of the input.
+    pub fn from_lines_with<'a, E, F>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        mut f: F,
+    ) -> Result<Self, ParseError>
+    where
+        E: Into<String>,
+        F: FnMut(char, UIndex2) -> Result<T, E>,
+    {
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines {
+            let row_width = line.text.chars().count() as u32;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(line.error(format!("a row of width {width}")));
+            }
+
+            for (x, (byte_idx, c)) in line.text.char_indices().enumerate() {
+                let tile = f(c, uidx2(x as u32, height))
+                    .map_err(|e| line.error_at(&line.text[byte_idx..byte_idx + c.len_utf8()], e))?;
+                data.push(tile);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            data,
+            dimension: uidx2(width.unwrap_or(0), height),
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parses a rectangular block of characters, one row per line.
+    pub fn parse(day: u32, input: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(day, input, |c, _| Ok::<_, String>(c))
+    }
 }
 
 impl<T> Index<UIndex2> for Grid<T> {
@@ -120,4 +244,80 @@ mod tests {
         //clearing the grid resets all the values to Default::default()
         assert_eq!(grid.get(uidx2(0, 0)), Some(&0));
     }
+
+    #[test]
+    fn grid_parse() {
+        let mut start = None;
+        let grid = Grid::from_str_with(0, "#.\nS#\n", |c, idx| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            'S' => {
+                start = Some(idx);
+                Ok(false)
+            }
+            _ => Err("a tile"),
+        })
+        .unwrap();
+
+        assert_eq!(grid.dimension(), uidx2(2, 2));
+        assert!(grid[uidx2(0, 0)]);
+        assert!(!grid[uidx2(1, 0)]);
+        assert!(grid[uidx2(1, 1)]);
+        assert_eq!(start, Some(uidx2(0, 1)));
+
+        let grid = Grid::parse(0, "ab\ncd\n\n  \n").unwrap();
+        assert_eq!(grid.dimension(), uidx2(2, 2));
+        let grid = Grid::parse(0, "a \n b\n\n").unwrap();
+        assert_eq!(grid[uidx2(1, 0)], ' ');
+    }
+
+    #[test]
+    fn grid_parse_errors() {
+        let err = Grid::parse(4, "abc\nab\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a row of width 3");
+
+        let err =
+            Grid::from_str_with(10, "12\n3x", |c, _| c.to_digit(10).ok_or("a digit")).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn grid_rays() {
+        let grid = Grid::parse(0, "abc\ndef\nghi").unwrap();
+        let ray = |idx, step| grid.ray(idx, step).map(|(_, c)| *c).collect::<String>();
+        assert_eq!(ray(uidx2(0, 0), Index2::new(1, 0)), "bc");
+        assert_eq!(ray(uidx2(0, 0), Index2::new(1, 1)), "ei");
+        assert_eq!(ray(uidx2(2, 2), Index2::new(0, -2)), "c");
+        assert_eq!(ray(uidx2(1, 1), Index2::new(-2, 1)), "");
+
+        let until = grid
+            .ray_until(uidx2(0, 0), Index2::new(0, 1), |c| *c == 'd')
+            .collect::<Vec<_>>();
+        assert_eq!(until, vec![(uidx2(0, 1), &'d')]);
+        let until = grid.ray_until(uidx2(0, 0), Index2::new(1, 0), |_| false);
+        assert_eq!(until.count(), 2);
+
+        assert_eq!(grid.ray(uidx2(1, 1), Index2::zero()).count(), 0);
+        let ray = grid.ray(uidx2(0, 1), Direction8::UpRight);
+        assert_eq!(ray.collect::<Vec<_>>(), vec![(uidx2(1, 0), &'b')]);
+        let ray = grid.ray(uidx2(2, 1), Direction::Left);
+        assert_eq!(ray.map(|(_, c)| *c).collect::<String>(), "ed");
+    }
+
+    #[test]
+    fn grid_neighbors() {
+        let grid = Grid::<u8>::new_with_default(uidx2(3, 3));
+        assert_eq!(grid.neighbor_indices4(uidx2(0, 0)).count(), 2);
+        assert_eq!(grid.neighbor_indices4(uidx2(1, 1)).count(), 4);
+        assert_eq!(grid.neighbor_indices8(uidx2(0, 0)).count(), 3);
+        assert_eq!(grid.neighbor_indices8(uidx2(1, 1)).count(), 8);
+        assert_eq!(grid.neighbor_indices8(uidx2(2, 1)).count(), 5);
+
+        let grid = Grid::parse(0, "abc\ndef").unwrap();
+        let neighbors = grid.neighbors4(uidx2(0, 1)).collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![(uidx2(0, 0), &'a'), (uidx2(1, 1), &'e')]);
+        let neighbors = grid.neighbors8(uidx2(0, 1)).map(|(_, c)| *c);
+        assert_eq!(neighbors.collect::<String>(), "abe");
+    }
 }
@@ -0,0 +1,249 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const RED: Rgb = Rgb::new(255, 0, 0);
+    pub const GREEN: Rgb = Rgb::new(0, 255, 0);
+    pub const BLUE: Rgb = Rgb::new(0, 0, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            #[cfg(feature = "png")]
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+/// An RGB image, usually created from a grid with [`Grid::to_image`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    dimension: UIndex2,
+    pixels: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    /// Maps every cell to one pixel of colour.
+    pub fn to_image(&self, f: impl Fn(UIndex2, &T) -> Rgb) -> Image {
+        let dimension = self.dimension();
+        let mut pixels = Vec::with_capacity(dimension.size() as usize);
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                let idx = uidx2(x, y);
+                pixels.push(f(idx, &self[idx]));
+            }
+        }
+        Image { dimension, pixels }
+    }
+}
+
+impl Image {
+    pub fn dimension(&self) -> UIndex2 {
+        self.dimension
+    }
+
+    pub fn pixel(&self, idx: UIndex2) -> Rgb {
+        self.pixels[(idx.y * self.dimension.x + idx.x) as usize]
+    }
+
+    /// Enlarges the image so each pixel becomes a `factor` x `factor` square; grids are tiny otherwise.
+    pub fn scaled(&self, factor: u32) -> Image {
+        let dimension = factor * self.dimension;
+        let mut pixels = Vec::with_capacity(dimension.size() as usize);
+        for y in 0..dimension.y {
+            for x in 0..dimension.x {
+                pixels.push(self.pixel(uidx2(x / factor, y / factor)));
+            }
+        }
+        Image { dimension, pixels }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|p| [p.r, p.g, p.b]).collect()
+    }
+
+    /// Writes a binary (P6) PPM.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(
+            writer,
+            "P6\n{} {}\n255\n",
+            self.dimension.x, self.dimension.y
+        )?;
+        writer.write_all(&self.bytes())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.dimension.x, self.dimension.y);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    pub fn write(&self, writer: impl Write, format: ImageFormat) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(writer),
+            #[cfg(feature = "png")]
+            ImageFormat::Png => self.write_png(writer),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>, format: ImageFormat) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer, format)?;
+        writer.flush()
+    }
+}
+
+/// Writes a numbered image sequence, e.g. one frame per simulation step.
+///
+/// Frames are named `<prefix>_00000.<ext>`, `<prefix>_00001.<ext>`, ... inside the output directory.
+#[derive(Debug)]
+pub struct FrameRecorder {
+    dir: PathBuf,
+    prefix: String,
+    format: ImageFormat,
+    scale: u32,
+    num_frames: usize,
+}
+
+impl FrameRecorder {
+    /// Creates the output directory if necessary.
+    pub fn new(dir: impl Into<PathBuf>, prefix: &str) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            prefix: prefix.to_owned(),
+            format: ImageFormat::Ppm,
+            scale: 1,
+            num_frames: 0,
+        })
+    }
+
+    pub fn with_format(mut self, format: ImageFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    /// Writes the next frame and returns its path.
+    pub fn record(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "{}_{:05}.{}",
+            self.prefix,
+            self.num_frames,
+            self.format.extension()
+        ));
+        if self.scale == 1 {
+            image.save(&path, self.format)?;
+        } else {
+            image.scaled(self.scale).save(&path, self.format)?;
+        }
+        self.num_frames += 1;
+        Ok(path)
+    }
+
+    /// Captures a grid as the next frame.
+    pub fn record_grid<T>(
+        &mut self,
+        grid: &Grid<T>,
+        f: impl Fn(UIndex2, &T) -> Rgb,
+    ) -> io::Result<PathBuf> {
+        self.record(&grid.to_image(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::from_str_with(0, "#.\n.#", |c, _| Ok::<_, String>(c == '#')).unwrap();
+        grid.to_image(|_, wall| if *wall { Rgb::BLACK } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn ppm_output() {
+        let mut out = vec![];
+        checkerboard().write_ppm(&mut out).unwrap();
+
+        let header = b"P6\n2 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        assert_eq!(
+            &out[header.len()..],
+            &[0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0]
+        );
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn png_output() {
+        let mut out = vec![];
+        checkerboard().write_png(&mut out).unwrap();
+        assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn scaled_image() {
+        let image = checkerboard().scaled(2);
+        assert_eq!(image.dimension(), uidx2(4, 4));
+        assert_eq!(image.pixel(uidx2(1, 1)), Rgb::BLACK);
+        assert_eq!(image.pixel(uidx2(2, 1)), Rgb::WHITE);
+    }
+
+    #[test]
+    fn frame_sequence() {
+        let dir = std::env::temp_dir().join(format!("aoc2024_frames_{}", std::process::id()));
+        let mut recorder = FrameRecorder::new(&dir, "step").unwrap();
+        let first = recorder.record(&checkerboard()).unwrap();
+        let second = recorder.record(&checkerboard()).unwrap();
+
+        assert_eq!(first, dir.join("step_00000.ppm"));
+        assert_eq!(second, dir.join("step_00001.ppm"));
+        assert!(second.exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
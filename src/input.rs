@@ -0,0 +1,173 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+/// Where to read a day's input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `dayNN.txt` in the input directory.
+    #[default]
+    Default,
+    /// A named variant in the input directory, e.g. `example` resolves to `example_dayNN.txt`.
+    Variant(String),
+    /// An explicit file.
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument: `-` is stdin, anything else a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(arg.into())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        day: u32,
+        path: PathBuf,
+    },
+    Io {
+        day: u32,
+        path: PathBuf,
+        source: io::Error,
+    },
+    Stdin {
+        day: u32,
+        source: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, path } => {
+                write!(
+                    f,
+                    "no input for day {day:02}: {} does not exist",
+                    path.display()
+                )
+            }
+            InputError::Io { day, path, source } => write!(
+                f,
+                "cannot read input for day {day:02} from {}: {source}",
+                path.display()
+            ),
+            InputError::Stdin { day, source } => {
+                write!(f, "cannot read input for day {day:02} from stdin: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { source, .. } | InputError::Stdin { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Resolves and reads puzzle inputs relative to an input directory.
+#[derive(Debug, Clone)]
+pub struct InputLocator {
+    dir: PathBuf,
+}
+
+impl Default for InputLocator {
+    fn default() -> Self {
+        Self::new(DEFAULT_INPUT_DIR)
+    }
+}
+
+impl InputLocator {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Uses the directory from [`INPUT_DIR_ENV`] if set, [`DEFAULT_INPUT_DIR`] otherwise.
+    pub fn from_env() -> Self {
+        match std::env::var_os(INPUT_DIR_ENV) {
+            Some(dir) => Self::new(dir),
+            None => Self::default(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The file a source refers to, or `None` for stdin.
+    pub fn path(&self, day: u32, source: &InputSource) -> Option<PathBuf> {
+        match source {
+            InputSource::Default => Some(self.dir.join(format!("day{day:02}.txt"))),
+            InputSource::Variant(name) => Some(self.dir.join(format!("{name}_day{day:02}.txt"))),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u32, source: &InputSource) -> Result<String, InputError> {
+        let Some(path) = self.path(day, source) else {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|source| InputError::Stdin { day, source })?;
+            return Ok(input);
+        };
+
+        std::fs::read_to_string(&path).map_err(|source| {
+            if source.kind() == io::ErrorKind::NotFound {
+                InputError::NotFound { day, path }
+            } else {
+                InputError::Io { day, path, source }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_paths() {
+        let locator = InputLocator::new("inputs");
+        assert_eq!(
+            locator.path(5, &InputSource::Default),
+            Some(PathBuf::from("inputs/day05.txt"))
+        );
+        assert_eq!(
+            locator.path(21, &InputSource::Variant("example2".to_owned())),
+            Some(PathBuf::from("inputs/example2_day21.txt"))
+        );
+        assert_eq!(
+            locator.path(3, &InputSource::from_arg("other.txt")),
+            Some(PathBuf::from("other.txt"))
+        );
+        assert_eq!(locator.path(3, &InputSource::from_arg("-")), None);
+    }
+
+    #[test]
+    fn missing_input_names_path() {
+        let locator = InputLocator::new("does/not/exist");
+        let err = locator.read(7, &InputSource::Default).unwrap_err();
+        assert!(matches!(err, InputError::NotFound { day: 7, .. }));
+        assert_eq!(
+            err.to_string(),
+            "no input for day 07: does/not/exist/day07.txt does not exist"
+        );
+    }
+}
@@ -1,3 +1,15 @@
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod days;
 pub mod direction;
+pub mod fixture;
 pub mod grid;
+pub mod image;
 pub mod index2;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod solution;
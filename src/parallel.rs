@@ -0,0 +1,144 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Number of worker threads; 0 means one per available core.
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets how many threads [`map_reduce`] uses; 0 restores the default of one per available core.
+/// Has no effect without the `parallel` feature.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+/// How many threads [`map_reduce`] uses, always 1 without the `parallel` feature.
+pub fn threads() -> usize {
+    if !cfg!(feature = "parallel") {
+        return 1;
+    }
+    match THREADS.load(Ordering::Relaxed) {
+        0 => std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    }
+}
+
+/// Folds contiguous chunks of `items` on separate scoped threads, each starting from `identity()`,
+/// then combines the chunks' results in order with `reduce`.
+///
+/// Because chunks are combined in item order, the result is the same for any number of threads
+/// as long as `fold` and `reduce` agree, i.e. `reduce` is associative with `identity()` as neutral
+/// element.
+pub fn map_reduce<T, R>(
+    items: &[T],
+    identity: impl Fn() -> R + Sync,
+    fold: impl Fn(R, &T) -> R + Sync,
+    reduce: impl Fn(R, R) -> R,
+) -> R
+where
+    T: Sync,
+    R: Send,
+{
+    map_reduce_with(threads(), items, identity, fold, reduce)
+}
+
+/// Like [`map_reduce`], on an explicit number of threads rather than [`threads()`].
+pub fn map_reduce_with<T, R>(
+    threads: usize,
+    items: &[T],
+    identity: impl Fn() -> R + Sync,
+    fold: impl Fn(R, &T) -> R + Sync,
+    reduce: impl Fn(R, R) -> R,
+) -> R
+where
+    T: Sync,
+    R: Send,
+{
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        return items.iter().fold(identity(), fold);
+    }
+
+    let chunk_size = items.len().div_ceil(threads);
+    let results = std::thread::scope(|scope| {
+        let workers = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().fold(identity(), &fold)))
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect::<Vec<_>>()
+    });
+    results.into_iter().reduce(reduce).unwrap()
+}
+
+/// Maps every item, possibly in parallel; the results keep the items' order.
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    map_reduce(
+        items,
+        Vec::new,
+        |mut results, item| {
+            results.push(f(item));
+            results
+        },
+        |mut a, b| {
+            a.extend(b);
+            a
+        },
+    )
+}
+
+/// Sums `f` over every item, possibly in parallel.
+pub fn sum<T>(items: &[T], f: impl Fn(&T) -> u64 + Sync) -> u64
+where
+    T: Sync,
+{
+    map_reduce(items, || 0, |sum, item| sum + f(item), |a, b| a + b)
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let items = (0..1000u64).collect::<Vec<_>>();
+        for threads in [1, 3, 8, 2000] {
+            let doubled = map_reduce_with(
+                threads,
+                &items,
+                Vec::new,
+                |mut v, x| {
+                    v.push(x * 2);
+                    v
+                },
+                |mut a, b| {
+                    a.extend(b);
+                    a
+                },
+            );
+            assert_eq!(doubled, (0..2000).step_by(2).collect::<Vec<_>>());
+            let sum = map_reduce_with(threads, &items, || 0, |s, x| s + x, |a, b| a + b);
+            assert_eq!(sum, 499500);
+            let concatenated = map_reduce_with(
+                threads,
+                &items[..20],
+                String::new,
+                |s, x| s + &x.to_string(),
+                |a, b| a + &b,
+            );
+            assert_eq!(concatenated, "012345678910111213141516171819");
+            let empty = map_reduce_with(threads, &[] as &[u64], || 0, |s, x| s + x, |a, b| a + b);
+            assert_eq!(empty, 0);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A malformed puzzle input, located by 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    /// The offending text.
+    pub found: String,
+    /// What the parser was looking for instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error for `found`, which should be a subslice of `input`; its position is derived from that.
+    pub fn in_input(day: u32, input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, found).unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            found: found.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error for something missing at the end of `input`.
+    pub fn at_end(day: u32, input: &str, expected: impl Into<String>) -> Self {
+        Self::in_input(day, input, &input[input.len()..], expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day{:02} line {}, column {}: expected {}, got `{}`",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `part` inside `whole`, if `part` is a subslice of it.
+fn offset_in(whole: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(whole.as_ptr() as usize)?;
+    (offset + part.len() <= whole.len()).then_some(offset)
+}
+
+/// A single input line, carrying what's needed to report errors inside it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u32,
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error covering the whole line.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.text, expected)
+    }
+
+    /// An error for `token`, which should be a subslice of this line.
+    pub fn error_at(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = offset_in(self.text, token).unwrap_or(0);
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            found: token.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error_at(token, expected))
+    }
+
+    /// The next token from `tokens`, or an error pointing at the end of the line if there is none.
+    pub fn next(
+        &self,
+        tokens: &mut impl Iterator<Item = &'a str>,
+        expected: &str,
+    ) -> Result<&'a str, ParseError> {
+        tokens
+            .next()
+            .ok_or_else(|| self.error_at(&self.text[self.text.len()..], expected))
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_position() {
+        let input = "1|2\n12|\n";
+        let line = lines(5, input).nth(1).unwrap();
+        let err = line.error("`a|b`");
+        assert_eq!(
+            err.to_string(),
+            "day05 line 2, column 1: expected `a|b`, got `12|`"
+        );
+
+        let err = line.error_at(&line.text[2..], "a number");
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = ParseError::in_input(5, input, &input[5..7], "a number");
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "2|"));
+    }
+
+    #[test]
+    fn missing_token() {
+        let line = lines(1, "3   ").next().unwrap();
+        let mut tokens = line.text.split_whitespace();
+        assert_eq!(line.next(&mut tokens, "a number"), Ok("3"));
+        let err = line.next(&mut tokens, "a number").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (5, ""));
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Write},
+};
+
+use crate::{
+    grid::Grid,
+    index2::{uidx2, UIndex2},
+};
+
+/// A text rendering of a grid, created by [`Grid::render`].
+///
+/// Each cell is drawn with the formatter, unless an overlay covers it; later overlays win over earlier ones.
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    formatter: F,
+    overlays: HashMap<UIndex2, char>,
+}
+
+impl<T> Grid<T> {
+    pub fn render<F: Fn(UIndex2, &T) -> char>(&self, formatter: F) -> Render<'_, T, F> {
+        Render {
+            grid: self,
+            formatter,
+            overlays: HashMap::new(),
+        }
+    }
+}
+
+impl<T, F: Fn(UIndex2, &T) -> char> Render<'_, T, F> {
+    /// Draws `c` on each of the given positions instead of the cell's own character, e.g. for a path.
+    pub fn overlay(mut self, positions: impl IntoIterator<Item = UIndex2>, c: char) -> Self {
+        self.overlays
+            .extend(positions.into_iter().map(|position| (position, c)));
+        self
+    }
+}
+
+impl<T, F: Fn(UIndex2, &T) -> char> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dimension = self.grid.dimension();
+        for y in 0..dimension.y {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+            for x in 0..dimension.x {
+                let idx = uidx2(x, y);
+                let c = match self.overlays.get(&idx) {
+                    Some(c) => *c,
+                    None => (self.formatter)(idx, &self.grid[idx]),
+                };
+                f.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(|_, c| *c).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_round_trip() {
+        let text = "#..\n.#.\n..#";
+        let grid = Grid::parse(0, text).unwrap();
+        assert_eq!(grid.to_string(), text);
+    }
+
+    #[test]
+    fn render_with_overlays() {
+        let grid = Grid::from_str_with(0, "#..\n...", |c, _| Ok::<_, String>(c == '#')).unwrap();
+        let rendered = grid
+            .render(|_, wall| if *wall { '#' } else { '.' })
+            .overlay([uidx2(1, 0), uidx2(1, 1)], 'O')
+            .overlay([uidx2(1, 1)], '@')
+            .to_string();
+        assert_eq!(rendered, "#O.\n.@.");
+    }
+}
@@ -0,0 +1,603 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::{self, Display},
+    hash::Hash,
+    iter::Sum,
+    ops::AddAssign,
+};
+
+/// The outcome of a search: the cost to every reached state, and how each one was first reached.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    /// Cost from the start for every state that was reached. For weighted searches that stopped at a goal,
+    /// states still waiting in the queue only have their best known cost so far.
+    pub distances: HashMap<S, u64>,
+    /// The state each reached state (other than the start) was reached from on a cheapest path.
+    pub predecessors: HashMap<S, S>,
+    /// The goal state the search stopped at, if any was reached.
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> SearchResult<S> {
+    /// The cost to the goal, if it was reached.
+    pub fn cost(&self) -> Option<u64> {
+        self.goal.as_ref().map(|goal| self.distances[goal])
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// The states from the start to `state`, both inclusive.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap()) {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The states from the start to the goal, both inclusive.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+///
+/// Stops at the first state satisfying `is_goal`; pass `|_| false` to explore everything reachable.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    distances.insert(start.clone(), 0);
+
+    let mut open = VecDeque::new();
+    open.push_back(start);
+
+    while let Some(state) = open.pop_front() {
+        if is_goal(&state) {
+            return SearchResult {
+                distances,
+                predecessors,
+                goal: Some(state),
+            };
+        }
+
+        let distance = distances[&state] + 1;
+        for next in neighbors(&state) {
+            if distances.contains_key(&next) {
+                continue;
+            }
+            distances.insert(next.clone(), distance);
+            predecessors.insert(next.clone(), state.clone());
+            open.push_back(next);
+        }
+    }
+
+    SearchResult {
+        distances,
+        predecessors,
+        goal: None,
+    }
+}
+
+/// Dijkstra's algorithm; `neighbors` returns each successor together with the cost of the step to it.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a goal, otherwise the result
+/// may not be optimal.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    distances.insert(start.clone(), 0);
+
+    // heap is max heap, Reverse to get min cost
+    let mut open = BinaryHeap::new();
+    open.push(Reverse(Entry {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }));
+
+    while let Some(Reverse(Entry { cost, state, .. })) = open.pop() {
+        if distances[&state] < cost {
+            // we've found a better path to this state in the meantime
+            continue;
+        }
+        if is_goal(&state) {
+            return SearchResult {
+                distances,
+                predecessors,
+                goal: Some(state),
+            };
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            if distances.get(&next).is_some_and(|&old| old <= next_cost) {
+                continue;
+            }
+            distances.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), state.clone());
+            open.push(Reverse(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            }));
+        }
+    }
+
+    SearchResult {
+        distances,
+        predecessors,
+        goal: None,
+    }
+}
+
+/// Every cheapest path from the start, as a DAG of predecessors. Created by [`dijkstra_all`].
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    pub start: S,
+    /// Cost from the start for every state that was reached.
+    pub distances: HashMap<S, u64>,
+    /// For each reached state other than the start, every state it's reached from on some cheapest path.
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// All goal states reachable at the lowest cost.
+    pub goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// The cost to the goals, if any was reached.
+    pub fn cost(&self) -> Option<u64> {
+        self.goals.first().map(|goal| self.distances[goal])
+    }
+
+    /// Every cheapest path from the start to `state`, both inclusive. The number of paths can grow
+    /// exponentially; use [`ShortestPaths::count_paths_to`] if only the number is needed.
+    pub fn paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        let mut ret = vec![];
+
+        // partial paths, built backwards from `state`
+        let mut open = vec![vec![state.clone()]];
+        while let Some(path) = open.pop() {
+            let last = path.last().unwrap();
+            match self.predecessors.get(last) {
+                Some(predecessors) => {
+                    for predecessor in predecessors {
+                        let mut path = path.clone();
+                        path.push(predecessor.clone());
+                        open.push(path);
+                    }
+                }
+                None if *last == self.start => {
+                    let mut path = path;
+                    path.reverse();
+                    ret.push(path);
+                }
+                None => {}
+            }
+        }
+        ret
+    }
+
+    /// Every cheapest path from the start to any of the goals.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        self.goals
+            .iter()
+            .flat_map(|goal| self.paths_to(goal))
+            .collect()
+    }
+
+    pub fn count_paths_to(&self, state: &S) -> PathCount {
+        self.count_paths_to_all(std::slice::from_ref(state))
+    }
+
+    /// The number of cheapest paths to any of the goals.
+    pub fn count_paths(&self) -> PathCount {
+        self.count_paths_to_all(&self.goals)
+    }
+
+    fn count_paths_to_all(&self, targets: &[S]) -> PathCount {
+        // a state's predecessors are always closer to the start, so counting in order of distance
+        // sees every predecessor before the state itself
+        let mut states = self
+            .states_on_paths_to(targets)
+            .into_iter()
+            .collect::<Vec<_>>();
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<S, PathCount> = HashMap::new();
+        for state in states {
+            let count = match self.predecessors.get(&state) {
+                Some(predecessors) => predecessors.iter().map(|p| &counts[p]).sum(),
+                None => PathCount::from(1),
+            };
+            counts.insert(state, count);
+        }
+
+        targets.iter().filter_map(|target| counts.get(target)).sum()
+    }
+
+    /// The union of all states on any cheapest path to a goal.
+    pub fn states_on_paths(&self) -> HashSet<S> {
+        self.states_on_paths_to(&self.goals)
+    }
+
+    /// The union of all cells visited by any cheapest path to a goal, where `key` maps a state to its cell,
+    /// e.g. dropping the facing from a `(position, facing)` state.
+    pub fn cells_on_paths<K: Eq + Hash>(&self, key: impl Fn(&S) -> K) -> HashSet<K> {
+        self.states_on_paths().iter().map(key).collect()
+    }
+
+    fn states_on_paths_to(&self, targets: &[S]) -> HashSet<S> {
+        let mut ret = HashSet::new();
+        let mut open = targets
+            .iter()
+            .filter(|target| self.distances.contains_key(target))
+            .cloned()
+            .collect::<Vec<_>>();
+        while let Some(state) = open.pop() {
+            if let Some(predecessors) = self.predecessors.get(&state) {
+                open.extend(
+                    predecessors
+                        .iter()
+                        .filter(|predecessor| !ret.contains(*predecessor))
+                        .cloned(),
+                );
+            }
+            ret.insert(state);
+        }
+        ret
+    }
+}
+
+/// Like [`dijkstra`], but keeps every predecessor on a cheapest path instead of just one, and finds every
+/// goal reachable at the lowest cost. Step costs must be positive.
+pub fn dijkstra_all<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> ShortestPaths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut distances = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut goals = vec![];
+    distances.insert(start.clone(), 0);
+
+    let mut open = BinaryHeap::new();
+    open.push(Reverse(Entry {
+        priority: 0,
+        cost: 0,
+        state: start.clone(),
+    }));
+
+    let mut best = None;
+    while let Some(Reverse(Entry { cost, state, .. })) = open.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            match distances.get(&next) {
+                Some(&old) if old < next_cost => {}
+                Some(&old) if old == next_cost => {
+                    // an alternate path with the same total cost
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    distances.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    open.push(Reverse(Entry {
+                        priority: next_cost,
+                        cost: next_cost,
+                        state: next,
+                    }));
+                }
+            }
+        }
+    }
+
+    ShortestPaths {
+        start,
+        distances,
+        predecessors,
+        goals,
+    }
+}
+
+/// A number of paths. Unbounded, since counts in even moderately sized grids overflow `u64`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PathCount {
+    // base 2^32 digits, least significant first, without trailing zeros
+    digits: Vec<u32>,
+}
+
+impl PathCount {
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.digits[..] {
+            [] => Some(0),
+            [low] => Some(low as u64),
+            [low, high] => Some(low as u64 | (high as u64) << 32),
+            _ => None,
+        }
+    }
+}
+
+impl From<u64> for PathCount {
+    fn from(value: u64) -> Self {
+        let mut digits = vec![value as u32, (value >> 32) as u32];
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+}
+
+impl AddAssign<&PathCount> for PathCount {
+    fn add_assign(&mut self, rhs: &PathCount) {
+        if self.digits.len() < rhs.digits.len() {
+            self.digits.resize(rhs.digits.len(), 0);
+        }
+        let mut carry = 0;
+        for (i, digit) in self.digits.iter_mut().enumerate() {
+            let sum = *digit as u64 + rhs.digits.get(i).copied().unwrap_or(0) as u64 + carry;
+            *digit = sum as u32;
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            self.digits.push(carry as u32);
+        }
+    }
+}
+
+impl<'a> Sum<&'a PathCount> for PathCount {
+    fn sum<I: Iterator<Item = &'a PathCount>>(iter: I) -> Self {
+        let mut ret = PathCount::default();
+        for count in iter {
+            ret += count;
+        }
+        ret
+    }
+}
+
+impl Display for PathCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 1_000_000_000;
+
+        // repeatedly divide by 10^9, collecting the remainders as 9-digit decimal chunks
+        let mut digits = self.digits.clone();
+        let mut chunks = vec![];
+        while !digits.is_empty() {
+            let mut remainder = 0;
+            for digit in digits.iter_mut().rev() {
+                let current = remainder << 32 | *digit as u64;
+                *digit = (current / CHUNK) as u32;
+                remainder = current % CHUNK;
+            }
+            chunks.push(remainder);
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+        }
+
+        let Some((first, rest)) = chunks.split_last() else {
+            return write!(f, "0");
+        };
+        write!(f, "{first}")?;
+        for chunk in rest.iter().rev() {
+            write!(f, "{chunk:09}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Queue entry, ordered by priority only so states don't need to implement `Ord`.
+struct Entry<S> {
+    priority: u64,
+    cost: u64,
+    state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        direction::Direction,
+        grid::Grid,
+        index2::{uidx2, UIndex2},
+    };
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+...#...";
+
+    fn open_neighbors(grid: &Grid<char>, idx: UIndex2) -> impl Iterator<Item = UIndex2> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |d| d.get_neighbor(idx, grid))
+            .filter(|n| grid[*n] == '.')
+    }
+
+    #[test]
+    fn bfs_maze() {
+        let grid = Grid::parse(0, MAZE).unwrap();
+        let target = uidx2(6, 0);
+        let result = bfs(
+            uidx2(0, 0),
+            |&idx| open_neighbors(&grid, idx),
+            |&idx| idx == target,
+        );
+
+        assert_eq!(result.cost(), Some(12));
+        let path = result.path().unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!(path.first(), Some(&uidx2(0, 0)));
+        assert_eq!(path.last(), Some(&target));
+
+        let everything = bfs(uidx2(0, 0), |&idx| open_neighbors(&grid, idx), |_| false);
+        assert_eq!(everything.goal, None);
+        assert_eq!(everything.distance(&uidx2(6, 4)), Some(10));
+        assert_eq!(everything.distance(&uidx2(2, 0)), None);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // a direct but expensive edge 0 -> 3, and a cheap detour
+        let edges: HashMap<u32, Vec<(u32, u64)>> = HashMap::from([
+            (0, vec![(1, 1), (3, 10)]),
+            (1, vec![(2, 2)]),
+            (2, vec![(3, 3)]),
+        ]);
+        let neighbors = |n: &u32| edges.get(n).cloned().unwrap_or_default();
+
+        let result = dijkstra(0, neighbors, |&n| n == 3);
+        assert_eq!(result.cost(), Some(6));
+        assert_eq!(result.path(), Some(vec![0, 1, 2, 3]));
+
+        let result = astar(0, neighbors, |&n| 3 - n as u64, |&n| n == 3);
+        assert_eq!(result.cost(), Some(6));
+        assert_eq!(result.path(), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn astar_on_grid() {
+        let grid = Grid::parse(0, MAZE).unwrap();
+        let target = uidx2(6, 4);
+        let result = astar(
+            uidx2(0, 0),
+            |&idx| open_neighbors(&grid, idx).map(|n| (n, 1)),
+            |idx| (target.x.abs_diff(idx.x) + target.y.abs_diff(idx.y)) as u64,
+            |&idx| idx == target,
+        );
+        assert_eq!(result.cost(), Some(10));
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        let grid = Grid::<char>::new_with_provider(uidx2(3, 3), || '.');
+        let target = uidx2(2, 2);
+        let paths = dijkstra_all(
+            uidx2(0, 0),
+            |&idx| open_neighbors(&grid, idx).map(|n| (n, 1)),
+            |&idx| idx == target,
+        );
+
+        assert_eq!(paths.cost(), Some(4));
+        assert_eq!(paths.goals, vec![target]);
+        assert_eq!(paths.count_paths().to_u64(), Some(6));
+        assert_eq!(paths.paths().len(), 6);
+        assert!(paths.paths().iter().all(|path| path.len() == 5));
+        assert_eq!(paths.states_on_paths().len(), 9);
+        assert_eq!(paths.count_paths_to(&uidx2(1, 1)).to_u64(), Some(2));
+    }
+
+    #[test]
+    fn all_shortest_paths_by_cell() {
+        // the same cell reached with two different "modes", both on a cheapest path to one of two goals
+        type State = (u32, bool);
+        let edges: HashMap<State, Vec<(State, u64)>> = HashMap::from([
+            ((0, false), vec![((1, false), 1), ((1, true), 1)]),
+            ((1, false), vec![((2, false), 1)]),
+            ((1, true), vec![((2, true), 1), ((3, true), 5)]),
+        ]);
+        let paths = dijkstra_all(
+            (0, false),
+            |n| edges.get(n).cloned().unwrap_or_default(),
+            |(n, _)| *n >= 2,
+        );
+
+        assert_eq!(paths.goals.len(), 2);
+        assert_eq!(paths.count_paths(), PathCount::from(2));
+        assert_eq!(paths.states_on_paths().len(), 5);
+        assert_eq!(paths.cells_on_paths(|(n, _)| *n), HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn path_count_overflowing_u64() {
+        let grid = Grid::<char>::new_with_provider(uidx2(40, 40), || '.');
+        let target = uidx2(39, 39);
+        let paths = dijkstra_all(
+            uidx2(0, 0),
+            |&idx| open_neighbors(&grid, idx).map(|n| (n, 1)),
+            |&idx| idx == target,
+        );
+
+        let count = paths.count_paths();
+        assert_eq!(count.to_u64(), None);
+        assert_eq!(count.to_string(), "27217014869199032015600");
+        assert_eq!(PathCount::default().to_string(), "0");
+        assert_eq!(PathCount::from(u64::MAX).to_u64(), Some(u64::MAX));
+    }
+}
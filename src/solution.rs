@@ -0,0 +1,292 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::parse::ParseError;
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+    /// The part has no implementation yet.
+    Unimplemented,
+    /// The part couldn't be solved for this input, with the reason.
+    Failed(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unimplemented => write!(f, "<unimplemented>"),
+            Answer::Failed(reason) => write!(f, "<failed: {reason}>"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: &[Self] = &[Self::One, Self::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u32) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+/// Values of puzzle parameters by name, see [`Solution::PARAMS`].
+pub type Params = BTreeMap<String, u64>;
+
+/// A single day's puzzle: parsing the input once, then solving both parts on the parsed input.
+pub trait Solution {
+    const DAY: u32;
+
+    /// Names of the parameters that the real input leaves implicit, such as the size of a grid.
+    /// The examples in a puzzle text are usually smaller, and override them with
+    /// [`set_param`](Self::set_param).
+    const PARAMS: &'static [&'static str] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Overrides the parameter `name`, which is one of [`PARAMS`](Self::PARAMS), on parsed input.
+    /// Returns why `value` can't be used, if it can't.
+    fn set_param(_input: &mut Self::Input, name: &str, _value: u64) -> Result<(), String> {
+        Err(format!("day {:02} has no parameter `{name}`", Self::DAY))
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+/// The answer to one part of a day, as produced by [`RegisteredSolution::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+}
+
+/// Answers of one run of a solution, with the wall time spent parsing and in each part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedRun {
+    pub parse_time: Duration,
+    pub results: Vec<PartResult>,
+    /// Time spent in each part, in the order of `results`.
+    pub part_times: Vec<Duration>,
+}
+
+impl TimedRun {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part_times.iter().sum::<Duration>()
+    }
+}
+
+/// Type-erased handle to a [`Solution`], used by the runner to dispatch on the day number.
+#[derive(Clone, Copy)]
+pub struct RegisteredSolution {
+    pub day: u32,
+    /// The solution's [`Solution::PARAMS`].
+    pub params: &'static [&'static str],
+    runner: fn(&str, &[Part], &Params) -> Result<TimedRun, ParseError>,
+}
+
+impl RegisteredSolution {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            params: S::PARAMS,
+            runner: run::<S>,
+        }
+    }
+
+    /// Parses `input` and solves the requested parts in order.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<PartResult>, ParseError> {
+        self.run_timed(input, parts).map(|run| run.results)
+    }
+
+    /// Like [`run`](Self::run), also measuring how long parsing and each part take.
+    pub fn run_timed(&self, input: &str, parts: &[Part]) -> Result<TimedRun, ParseError> {
+        (self.runner)(input, parts, &Params::new())
+    }
+
+    /// Like [`run`](Self::run), overriding some of the solution's [`params`](Self::params) first.
+    ///
+    /// If a parameter is unknown or its value is rejected, every part fails with the reason.
+    pub fn run_with_params(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: &Params,
+    ) -> Result<Vec<PartResult>, ParseError> {
+        (self.runner)(input, parts, params).map(|run| run.results)
+    }
+}
+
+impl fmt::Debug for RegisteredSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredSolution")
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part], params: &Params) -> Result<TimedRun, ParseError> {
+    let start = Instant::now();
+    let mut input = S::parse(input)?;
+    let rejected = params
+        .iter()
+        .find_map(|(name, value)| set_param::<S>(&mut input, name, *value).err());
+    let parse_time = start.elapsed();
+
+    let mut results = Vec::with_capacity(parts.len());
+    let mut part_times = Vec::with_capacity(parts.len());
+    for part in parts {
+        let start = Instant::now();
+        let answer = match (&rejected, part) {
+            (Some(reason), _) => Answer::Failed(reason.clone()),
+            (None, Part::One) => S::part1(&input),
+            (None, Part::Two) => S::part2(&input),
+        };
+        part_times.push(start.elapsed());
+        results.push(PartResult {
+            part: *part,
+            answer,
+        });
+    }
+    Ok(TimedRun {
+        parse_time,
+        results,
+        part_times,
+    })
+}
+
+fn set_param<S: Solution>(input: &mut S::Input, name: &str, value: u64) -> Result<(), String> {
+    if !S::PARAMS.contains(&name) {
+        return Err(format!("day {:02} has no parameter `{name}`", S::DAY));
+    }
+    S::set_param(input, name, value)
+        .map_err(|reason| format!("invalid value {value} for parameter `{name}`: {reason}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+
+        const PARAMS: &'static [&'static str] = &["extra"];
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.split(',').map(|x| x.parse().unwrap()).collect())
+        }
+
+        fn set_param(input: &mut Self::Input, _name: &str, value: u64) -> Result<(), String> {
+            if value == 0 {
+                return Err("adding nothing".to_owned());
+            }
+            input.push(value as u32);
+            Ok(())
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn run_registered_solution() {
+        let solution = RegisteredSolution::new::<Sum>();
+        let results = solution.run("1,2,3", Part::ALL).unwrap();
+        assert_eq!(
+            results,
+            vec![
+                PartResult {
+                    part: Part::One,
+                    answer: Answer::Number(6),
+                },
+                PartResult {
+                    part: Part::Two,
+                    answer: Answer::Unimplemented,
+                },
+            ]
+        );
+
+        let params = Params::from([("extra".to_owned(), 4)]);
+        let results = solution
+            .run_with_params("1,2,3", &[Part::One], &params)
+            .unwrap();
+        assert_eq!(results[0].answer, Answer::Number(10));
+
+        let params = Params::from([("extra".to_owned(), 0)]);
+        let results = solution
+            .run_with_params("1", &[Part::One], &params)
+            .unwrap();
+        assert_eq!(
+            results[0].answer,
+            Answer::Failed("invalid value 0 for parameter `extra`: adding nothing".to_owned())
+        );
+        let params = Params::from([("size".to_owned(), 7)]);
+        let results = solution.run_with_params("1", Part::ALL, &params).unwrap();
+        assert!(results
+            .iter()
+            .all(|r| r.answer == Answer::Failed("day 00 has no parameter `size`".to_owned())));
+    }
+}
@@ -0,0 +1,48 @@
+//! Checks every day against the confirmed answers in `answers.toml` (or the file named by `AOC_ANSWERS_FILE`).
+//! Days whose input is absent are skipped, so this passes on a checkout without inputs.
+
+use aoc2024::{
+    answers::{Answers, Verification},
+    days,
+    input::{InputLocator, InputSource},
+};
+
+fn verify_day(day: u32) {
+    let answers = Answers::load_from_env().unwrap();
+    let solution = days::find(day).unwrap();
+    let locator = InputLocator::from_env();
+    let verification = Verification::collect(std::slice::from_ref(solution), &answers, |day| {
+        locator.read(day, &InputSource::Default)
+    });
+    if !verification.skipped.is_empty() {
+        eprintln!(
+            "skipping day {day:02}: no input in {}",
+            locator.dir().display()
+        );
+    }
+    assert!(verification.passed(), "{verification}");
+}
+
+macro_rules! verify_days {
+    ($($name:ident => $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                verify_day($day);
+            }
+        )*
+    };
+}
+
+verify_days! {
+    day01 => 1, day02 => 2, day03 => 3, day04 => 4, day05 => 5,
+    day06 => 6, day07 => 7, day08 => 8, day09 => 9, day10 => 10,
+    day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+    day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+    day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+}
+
+#[test]
+fn every_day_is_verified() {
+    assert_eq!(days::ALL.len(), 25, "add new days to `verify_days!`");
+}